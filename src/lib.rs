@@ -1,5 +1,5 @@
-//! Allows to compute on big numbers. No negative numbers support. Provides only some
-//! basic mathematical functions.
+//! Allows to compute on big numbers. Negative numbers are supported by `SignedPlacesRow`.
+//! Provides only some basic mathematical functions.
 
 #![no_std]
 
@@ -239,7 +239,7 @@ impl From<u128> for PlacesRow {
 
 /// Represents 1,000 numbers of √10 ≈ 3.16.
 ///
-/// Check with `fn ord_of_mag`.
+/// Truncated, thus lesser than √10 ⋅10⁹⁹⁹. Check with `fn ord_of_mag`.
 pub const SQUARE_ROOT_TEN_COMPARATOR: &str = "3162277660168379331998893544432718533719555139325216826857504852792594438639238221344248108379300295187347284152840055148548856030453880014690519596700153903344921657179259940659150153474113339484124085316929577090471576461044369257879062037808609941828371711548406328552999118596824564203326961604691314336128949791890266529543612676178781350061388186278580463683134952478031143769334671973819513185678403231241795402218308045872844614600253577579702828644029024407977896034543989163349222652612067792651676031048436697793756926155720500369894909469421850007358348844643882731109289109042348054235653403907274019786543725939641726001306990000955784463109626790694418336130181302894541703315807731626386395193793704654765220632063686587197822049312426053454111609356979828132452297000798883523759585328579251362964686511497675217123459559238039375625125369855194955325099947038843990336466165470647234999796132343403021857052187836676345789510732982875157945215771652139626324438399018484560935762602";
 
 /// Order of magnitude computational kind.
@@ -260,12 +260,13 @@ pub enum Oom {
     Undefined,
     /// Precise _oom_.
     ///
-    /// Check with `Approx(usize)` variant.
+    /// `fn ord_of_mag` produces this variant for any `PlacesRow` other than nought.
     Precise(usize),
-    /// Approximated _oom_ is result of operation on `PlacesRow` requiring
-    /// greater precision than provided by `SQUARE_ROOT_TEN_COMPARATOR`.
+    /// Approximated _oom_.
     ///
-    /// Check with `fn ord_of_mag`.
+    /// Not produced by `fn ord_of_mag` anymore since `Strict` kind evaluation is exact
+    /// for any `PlacesRow`.
+    #[deprecated(note = "`fn ord_of_mag` evaluates precisely, check with `Precise(usize)`.")]
     Approx(usize),
 }
 
@@ -277,10 +278,9 @@ pub enum Oom {
 ///
 /// Then _i_ is order of magnitude of such number.
 ///
-/// `Strict` kind evaluation is precise up to 1,000 numbers of `SQUARE_ROOT_TEN_COMPARATOR`.
-/// Any `num` requiring higher precision is considered to be of higher order. That
-/// means its order of magnitude is arranged equal to its decimal places count
-/// and is reported as `Oom::Approx(usize)`.
+/// `Strict` kind evaluation is precise for any `num`. `SQUARE_ROOT_TEN_COMPARATOR` is
+/// used for relation first, `num` requiring higher precision is squared and
+/// u ≥ √10 ⇔ u² ≥ 10 is evaluated then.
 ///
 /// Returns `Oom` enumeration.
 pub fn ord_of_mag(num: &PlacesRow, kind: OomKind) -> Oom {
@@ -322,23 +322,18 @@ pub fn ord_of_mag(num: &PlacesRow, kind: OomKind) -> Oom {
         cmp_ix = cmp_ix + 1;
     }
 
-    let (num_less, precise) = if let Some(l) = num_less {
-        (l, true)
-    } else {
-        let ord = row_len.cmp(&cmp_len);
-        match ord {
-            Ordering::Greater => (false, kind == OomKind::Loose),
-            Ordering::Less => (true, true),
-            _ => (false, true),
-        }
+    let num_less = match num_less {
+        Some(l) => l,
+        // `num` is prefix of or equal to `cmp`, or `cmp` is prefix of `num`
+        None => match kind {
+            // u ≥ √10 ⋅10ⁱ ⟺ u² ≥ 10²ⁱ⁺¹ ⟺ u² has 2i +2 places
+            OomKind::Strict => mul(num, num).len() < 2 * row_len,
+            OomKind::Loose => false,
+        },
     };
 
     let oom = if num_less { row_len - 1 } else { row_len };
-
-    match precise {
-        true => Oom::Precise(oom),
-        false => Oom::Approx(oom),
-    }
+    Oom::Precise(oom)
 }
 
 /// Relation enumeration.
@@ -459,7 +454,7 @@ fn rel_dec_raw(r1: &RawRow, r2: &RawRow) -> RelDec {
     }
 }
 
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 /// Computes `addend1` and `addend2` sum.
 ///
@@ -553,6 +548,12 @@ pub fn mul(factor1: &PlacesRow, factor2: &PlacesRow) -> PlacesRow {
         None => {}
     };
 
+    if factor1.len() >= KARATSUBA_THRESHOLD && factor2.len() >= KARATSUBA_THRESHOLD {
+        return Row {
+            row: karatsuba(factor1, factor2),
+        };
+    }
+
     mulmul(factor1, factor2, 1)
 }
 
@@ -618,7 +619,7 @@ pub fn divrem(dividend: &PlacesRow, divisor: &PlacesRow) -> Option<(PlacesRow, P
         None => {}
     }
 
-    let remratio = subtraction(&dividend, &divisor, true);
+    let remratio = division(dividend, divisor);
     Some((Row { row: remratio.1 }, Row { row: remratio.0 }))
 }
 
@@ -646,213 +647,5530 @@ fn divrem_shortcut(dividend: &RawRow, divisor: &RawRow) -> Option<Option<(Row, R
     Some(Some(shortcut))
 }
 
-/// Combined method allows to compute multiplication and power using shared code.
-///
-/// Space for effecient power computation?
-///   🡺 Inspect log₂ power speed up.
-fn mulmul(row1: &RawRow, row2: &RawRow, times: u16) -> Row {
-    let (mpler, mut mcand) = (row1, row2.clone());
+/// Sign enumeration.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sign {
+    /// Nought or positive number.
+    Plus,
+    /// Negative number.
+    Minus,
+}
 
-    #[cfg(feature = "one-power-mulmul-support")]
-    if times == 0 {
-        return Row { row: mcand };
+impl Sign {
+    fn flip(self) -> Sign {
+        match self {
+            Sign::Plus => Sign::Minus,
+            Sign::Minus => Sign::Plus,
+        }
     }
 
-    let mpler_len = mpler.len();
+    fn product(self, other: Sign) -> Sign {
+        if self == other {
+            Sign::Plus
+        } else {
+            Sign::Minus
+        }
+    }
+}
 
-    // intermediate product of `mcand` and `mpler`
-    let mut i_product = Vec::with_capacity(0);
-    // intermediate sum of intermediate products
-    let mut i_sum = Vec::with_capacity(0);
+/// `SignedPlacesRow` represents signed number as sign and `PlacesRow` magnitude.
+///
+/// Nought is always of `Sign::Plus`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignedPlacesRow {
+    sign: Sign,
+    mag: PlacesRow,
+}
 
-    let mut cntr = 0;
-    loop {
-        let mcand_len = mcand.len();
+type SRow = SignedPlacesRow;
 
-        // avoids repetetive reallocations
-        // +1 stands for contigent new place
-        i_product.reserve(mcand_len + 1);
-        // avoids repetetive reallocations
-        // places count of product cannot
-        // be greater than sum of places of operands
-        i_sum.reserve(mcand_len + mpler_len);
+impl SignedPlacesRow {
+    /// Ctor from `sign` and magnitude `mag`.
+    ///
+    /// `Sign::Minus` of nought `mag` is replaced with `Sign::Plus`.
+    pub fn new(sign: Sign, mag: PlacesRow) -> Self {
+        let sign = if mag.is_nought() { Sign::Plus } else { sign };
+        SRow { sign, mag }
+    }
 
-        #[cfg(test)]
-        let i_product_ptr = i_product.as_ptr();
+    /// Handy ctor for usage with _classic_ primitive numeric data type.
+    pub fn new_from_num(num: i128) -> Self {
+        let sign = if num < 0 { Sign::Minus } else { Sign::Plus };
+        SRow::new(sign, Row::new_from_num(num.unsigned_abs()))
+    }
 
-        #[cfg(test)]
-        let i_sum_ptr = i_sum.as_ptr();
+    /// Handy ctor for usage with long numbers.
+    ///
+    /// `s` can start with `-` or `+` sign. Only digits are allowed then. Leading zeros are
+    /// ommitted.
+    ///
+    /// Returns `SignedPlacesRow` or index in `s` where uncovertable `char` was
+    /// encountered. `None` for empty string or sign only.
+    pub fn new_from_str(s: &str) -> Result<Self, Option<usize>> {
+        let (sign, digits) = sign_split(s);
+
+        let offset = s.len() - digits.len();
+        let mag = Row::new_from_str(digits).map_err(|e| e.map(|e| e + offset))?;
+        Ok(SRow::new(sign, mag))
+    }
 
-        for offset in 0..mpler_len {
-            product(mpler[offset], &mcand, &mut i_product);
-            addition(&i_product, None, &mut i_sum, offset);
-            i_product.clear();
+    /// Returns `String` representation. Negative number is prefixed with `-`.
+    pub fn to_number(&self) -> String {
+        let mag = self.mag.to_number();
+        match self.sign {
+            Sign::Plus => mag,
+            Sign::Minus => {
+                let mut number = String::from("-");
+                number.push_str(&mag);
+                number
+            }
         }
+    }
 
-        #[cfg(test)]
-        assert!(i_product_ptr == i_product.as_ptr());
+    /// Returns `Sign` of number.
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
 
-        #[cfg(test)]
-        assert!(i_sum_ptr == i_sum.as_ptr());
+    /// Returns magnitude of number.
+    pub fn mag(&self) -> &PlacesRow {
+        &self.mag
+    }
 
-        cntr += 1;
-        if cntr == times {
-            mcand = i_sum;
-            break;
+    /// Returns absolute value.
+    pub fn abs(&self) -> SignedPlacesRow {
+        SRow::new(Sign::Plus, self.mag.clone())
+    }
+
+    /// Returns `-1` for negative number, `0` for nought, `1` for positive number.
+    pub fn signum(&self) -> i8 {
+        match self.sign {
+            _ if self.mag.is_nought() => 0,
+            Sign::Plus => 1,
+            Sign::Minus => -1,
         }
+    }
 
-        mcand.clear();
-        let swap = mcand;
-        mcand = i_sum;
-        i_sum = swap;
+    /// Returns additive inverse.
+    pub fn neg(&self) -> SignedPlacesRow {
+        SRow::new(self.sign.flip(), self.mag.clone())
     }
 
-    // useless when both of factors cannot be nought
-    shrink_to_fit_raw(&mut mcand);
-    Row { row: mcand }
-}
+    /// `true` if and only if `SignedPlacesRow` is _unity_ value.
+    pub fn is_unity(&self) -> bool {
+        self.sign == Sign::Plus && self.mag.is_unity()
+    }
 
-/// Computes product of `mpler` and `mcand`.
-fn product(mpler: u8, mcand: &RawRow, product: &mut RawRow) {
-    let mut takeover = 0;
+    /// `true` if and only if `SignedPlacesRow` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.mag.is_nought()
+    }
 
-    // runs in vain for `mpler` = 0
-    //   🡺 inspect possibilities
-    for &num in mcand {
-        // each `prod` can be immediately added to intermediate sum
-        //   🡺 inspect this option
-        let prod = mpler * num;
-        let prod = ones(prod, &mut takeover);
-        product.push(prod);
+    /// Returns unity `SignedPlacesRow`.
+    pub fn unity() -> SignedPlacesRow {
+        SRow::new(Sign::Plus, Row::unity())
     }
 
-    if takeover != 0 {
-        product.push(takeover);
+    /// Returns nought `SignedPlacesRow`.
+    pub fn nought() -> SignedPlacesRow {
+        SRow::new(Sign::Plus, Row::nought())
     }
 }
 
-/// Adds `addend_1` to `sum` or adds `addend_1` and `addend_2` sum into `sum`.
-///
-/// Precise expectations must be upkept when adding 2 addends: sum is assumed to be empty, `addend_1` to be longer or equal of numbers and offset to be `0`.
-fn addition(addend_1: &RawRow, addend_2: Option<&RawRow>, sum: &mut RawRow, offset: usize) {
-    let addend_1_len = addend_1.len();
+impl core::fmt::Display for SignedPlacesRow {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
 
-    let (addend_2_ptr, addend_2_len) = if let Some(addend) = addend_2 {
-        (addend.as_ptr(), addend.len())
+impl From<i128> for SignedPlacesRow {
+    /// Converts `value` into `SignedPlacesRow`.
+    fn from(value: i128) -> Self {
+        Self::new_from_num(value)
+    }
+}
+
+impl From<PlacesRow> for SignedPlacesRow {
+    /// Converts `value` into nonnegative `SignedPlacesRow`.
+    fn from(value: PlacesRow) -> Self {
+        Self::new(Sign::Plus, value)
+    }
+}
+
+/// Splits `s` into optional leading sign and rest.
+fn sign_split(s: &str) -> (Sign, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (Sign::Minus, rest)
+    } else if let Some(rest) = s.strip_prefix('+') {
+        (Sign::Plus, rest)
     } else {
-        (sum.as_ptr(), sum.len())
+        (Sign::Plus, s)
+    }
+}
+
+/// Computes `num` ⋅10ᵖˡᵃᶜᵉˢ by place shift.
+fn shift_places(num: &Row, places: usize) -> Row {
+    if num.is_nought() || places == 0 {
+        return num.clone();
+    }
+
+    let mut row = vec![0; places];
+    row.extend_from_slice(&num.row);
+    Row { row }
+}
+
+/// Parses `s` with leading sign and one decimal point into coefficient and fractional
+/// places count.
+///
+/// Errors are same as for `SignedPlacesRow::new_from_str`.
+fn decimal_split(s: &str) -> Result<(SRow, usize), Option<usize>> {
+    let Some(inx) = s.find('.') else {
+        return Ok((SRow::new_from_str(s)?, 0));
     };
 
-    let mut takeover = 0;
-    let mut addend_1_inx = 0;
-    let mut addend_2_inx = offset;
+    let (sign, int) = sign_split(&s[..inx]);
+    let offset = inx - int.len();
+    let int = Row::new_from_str(int).map_err(|e| e.map(|e| e + offset))?;
 
-    loop {
-        let addend_1_available = addend_1_inx < addend_1_len;
-        if !addend_1_available && takeover == 0 {
-            break;
-        }
+    let frac = &s[inx + 1..];
+    let frac_row = Row::new_from_str(frac).map_err(|e| e.map(|e| e + inx + 1))?;
 
-        let addend_1_num = if addend_1_available {
-            addend_1[addend_1_inx]
-        } else {
-            0
-        };
+    // a.b = a ⋅10ᵏ +b
+    let coef = add(&shift_places(&int, frac.len()), &frac_row);
+    Ok((SRow::new(sign, coef), frac.len()))
+}
 
-        let addend_2_num = if addend_2_inx < addend_2_len {
-            unsafe { addend_2_ptr.offset(addend_2_inx as isize).read() }
-        } else {
-            0
-        };
+/// Checks relation of `num` to `comparand`.
+///
+/// Decimal difference information relates to magnitudes and is available only for
+/// numbers of same sign.
+///
+/// Returns `Rel` relation.
+pub fn rel_signed(num: &SignedPlacesRow, comparand: &SignedPlacesRow) -> Rel {
+    match (num.sign, comparand.sign) {
+        (Sign::Plus, Sign::Plus) => rel(&num.mag, &comparand.mag),
+        (Sign::Minus, Sign::Minus) => match rel(&num.mag, &comparand.mag) {
+            Rel::Greater(c) => Rel::Lesser(c),
+            Rel::Lesser(c) => Rel::Greater(c),
+            Rel::Equal => Rel::Equal,
+        },
+        (Sign::Plus, Sign::Minus) => Rel::Greater(None),
+        (Sign::Minus, Sign::Plus) => Rel::Lesser(None),
+    }
+}
 
-        let add = ones(addend_2_num + addend_1_num, &mut takeover);
-        if let Some(refer) = sum.get_mut(addend_2_inx) {
-            *refer = add;
-        } else {
-            sum.push(add);
-        }
+/// Computes `addend1` and `addend2` sum.
+///
+/// Returns `SignedPlacesRow` with result.
+pub fn add_signed(addend1: &SignedPlacesRow, addend2: &SignedPlacesRow) -> SignedPlacesRow {
+    if addend1.sign == addend2.sign {
+        return SRow::new(addend1.sign, add(&addend1.mag, &addend2.mag));
+    }
 
-        addend_1_inx += 1;
-        addend_2_inx += 1;
+    // a +(-b) = a -b, a ≥ b
+    // a +(-b) = -(b -a), a < b
+    match sub(&addend1.mag, &addend2.mag) {
+        Some(diff) => SRow::new(addend1.sign, diff),
+        None => SRow::new(addend2.sign, sub(&addend2.mag, &addend1.mag).unwrap()),
     }
 }
 
-/// For difference computation applies precondition minuend ≥ subtrahend.
-/// Returns difference/remainder and ration in order.
-//
-// NOTE: Support for longer subtrahend implies extended guard condition on
-// correction `inx < subtrahend_len && inx < minuend_len`. See feature 'shorter-dividend-support'.
-fn subtraction(minuend: &RawRow, subtrahend: &RawRow, remainder: bool) -> (RawRow, RawRow) {
-    let mut diffrem_populated = false;
+/// Computes `minuend` and `subtrahend` difference.
+///
+/// Returns `SignedPlacesRow` with result.
+pub fn sub_signed(minuend: &SignedPlacesRow, subtrahend: &SignedPlacesRow) -> SignedPlacesRow {
+    add_signed(minuend, &subtrahend.neg())
+}
 
-    let minuend_len = minuend.len();
-    let subtrahend_len = subtrahend.len();
+/// Computes `factor1` and `factor2` product.
+///
+/// Returns `SignedPlacesRow` with result.
+pub fn mul_signed(factor1: &SignedPlacesRow, factor2: &SignedPlacesRow) -> SignedPlacesRow {
+    let sign = factor1.sign.product(factor2.sign);
+    SRow::new(sign, mul(&factor1.mag, &factor2.mag))
+}
 
-    let mut diffrem = vec![0; minuend_len];
-    let diffrem_ptr = diffrem.as_ptr();
-    let mut minuend_ptr = minuend.as_ptr();
+/// Computes power `pow` of `base`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `SignedPlacesRow` with result.
+pub fn pow_signed(base: &SignedPlacesRow, pow: u16) -> SignedPlacesRow {
+    let sign = if pow.is_multiple_of(2) {
+        Sign::Plus
+    } else {
+        base.sign
+    };
+    SRow::new(sign, crate::pow(&base.mag, pow))
+}
 
-    let mut ratio = nought_raw();
-    let one = vec![1; 1];
-    let mut takeover;
-    let mut inx;
-    loop {
-        takeover = 0;
-        inx = 0;
+/// Computes `dividend` and `divisor` ratio and remainder using truncated division.
+///
+/// Ratio is rounded toward nought, remainder has sign of `dividend`.
+///
+/// Returns tuple with `SignedPlacesRow` ratio and `SignedPlacesRow` remainder in order or
+/// `None` when `divisor` is nought.
+pub fn divrem_signed(
+    dividend: &SignedPlacesRow,
+    divisor: &SignedPlacesRow,
+) -> Option<(SignedPlacesRow, SignedPlacesRow)> {
+    let (ratio, rem) = divrem(&dividend.mag, &divisor.mag)?;
+    let sign = dividend.sign.product(divisor.sign);
+
+    Some((SRow::new(sign, ratio), SRow::new(dividend.sign, rem)))
+}
 
-        while inx < minuend_len {
-            let s_num = if inx < subtrahend_len {
-                subtrahend[inx]
-            } else if takeover == 0 && diffrem_populated {
-                break;
-            } else {
-                0
-            };
+/// Computes `dividend` and `divisor` ratio and remainder using Euclidean division.
+///
+/// Remainder r is never negative, 0 ≤ r < |divisor|.
+///
+/// Returns tuple with `SignedPlacesRow` ratio and `PlacesRow` remainder in order or `None`
+/// when `divisor` is nought.
+pub fn divrem_euclid(
+    dividend: &SignedPlacesRow,
+    divisor: &SignedPlacesRow,
+) -> Option<(SignedPlacesRow, PlacesRow)> {
+    let (ratio, rem) = divrem(&dividend.mag, &divisor.mag)?;
+    let sign = dividend.sign.product(divisor.sign);
+
+    if dividend.sign == Sign::Plus || rem.is_nought() {
+        return Some((SRow::new(sign, ratio), rem));
+    }
 
-            let mut m_num = unsafe { minuend_ptr.offset(inx as isize).read() };
+    // -a = -q ⋅b -r = -(q +1) ⋅b +(b -r)
+    let rem = sub(&divisor.mag, &rem).unwrap();
+    let ratio = add(&ratio, &Row::unity());
 
-            let total_s = s_num + takeover;
-            takeover = if m_num < total_s {
-                m_num += 10;
-                1
-            } else {
-                0
-            };
+    Some((SRow::new(sign, ratio), rem))
+}
 
-            diffrem[inx] = m_num - total_s;
-            inx += 1;
-        }
+/// `Ratio` represents rational number as `SignedPlacesRow` numerator and `PlacesRow`
+/// denominator.
+///
+/// Ratio is always kept reduced, denominator is positive.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ratio {
+    num: SignedPlacesRow,
+    den: PlacesRow,
+}
 
-        // existing remainder implies _minuend_ exhaustion
-        // thus remainder is one turn more than is correct
-        if takeover == 1 {
-            inx = 0;
-            takeover = 0;
-            while inx < subtrahend_len {
-                let correction = diffrem[inx] + subtrahend[inx];
-                diffrem[inx] = ones(correction, &mut takeover);
-                inx += 1;
-            }
+impl Ratio {
+    /// Ctor from numerator `num` and denominator `den`.
+    ///
+    /// Returns reduced `Ratio` or `None` when `den` is nought.
+    pub fn new(num: SignedPlacesRow, den: PlacesRow) -> Option<Self> {
+        if den.is_nought() {
+            return None;
+        }
 
-            truncate_leading_raw(&mut diffrem, 9, inx);
-            break;
+        let divisor = gcd(&num.mag, &den);
+        if divisor.is_unity() {
+            return Some(Ratio { num, den });
         }
 
-        addition(&one, None, &mut ratio, 0);
+        let num_mag = divrem(&num.mag, &divisor).unwrap().0;
+        let den = divrem(&den, &divisor).unwrap().0;
 
-        if !remainder {
-            break;
+        Some(Ratio {
+            num: SRow::new(num.sign, num_mag),
+            den,
+        })
+    }
+
+    /// Handy ctor for usage with _classic_ primitive numeric data types.
+    ///
+    /// Returns reduced `Ratio` or `None` when `den` is `0`.
+    pub fn new_from_num(num: i128, den: u128) -> Option<Self> {
+        Ratio::new(SRow::new_from_num(num), Row::new_from_num(den))
+    }
+
+    /// Handy ctor for usage with long numbers.
+    ///
+    /// `s` can be integer like `"-12"`, fraction like `"-3/4"` or decimal like `"-0.125"`.
+    /// Numerator, integer or decimal can start with `-` or `+` sign.
+    ///
+    /// Returns reduced `Ratio` or index in `s` where uncovertable `char` was encountered.
+    /// `None` for empty string, missing digits or nought denominator.
+    pub fn new_from_str(s: &str) -> Result<Self, Option<usize>> {
+        if let Some(inx) = s.find('/') {
+            let num = SRow::new_from_str(&s[..inx])?;
+            let den = &s[inx + 1..];
+            let den = Row::new_from_str(den).map_err(|e| e.map(|e| e + inx + 1))?;
+
+            return Ratio::new(num, den).ok_or(None);
         }
 
-        if !diffrem_populated {
-            minuend_ptr = diffrem_ptr;
-            diffrem_populated = true;
+        // a.b = (a ⋅10ᵏ +b) ÷10ᵏ
+        let (num, places) = decimal_split(s)?;
+        Ratio::new(num, ten_power(places)).ok_or(None)
+    }
+
+    /// Returns `String` representation, `"a/b"` or `"a"` when denominator is unity.
+    pub fn to_number(&self) -> String {
+        let mut number = self.num.to_number();
+        if !self.den.is_unity() {
+            number.push('/');
+            number.push_str(&self.den.to_number());
         }
+
+        number
     }
 
-    shrink_to_fit_raw(&mut diffrem);
-    (diffrem, ratio)
-}
+    /// Returns numerator.
+    pub fn numer(&self) -> &SignedPlacesRow {
+        &self.num
+    }
+
+    /// Returns denominator.
+    pub fn denom(&self) -> &PlacesRow {
+        &self.den
+    }
+
+    /// Returns absolute value.
+    pub fn abs(&self) -> Ratio {
+        Ratio {
+            num: self.num.abs(),
+            den: self.den.clone(),
+        }
+    }
+
+    /// Returns additive inverse.
+    pub fn neg(&self) -> Ratio {
+        Ratio {
+            num: self.num.neg(),
+            den: self.den.clone(),
+        }
+    }
+
+    /// Returns multiplicative inverse or `None` for nought.
+    pub fn recip(&self) -> Option<Ratio> {
+        Ratio::new(
+            SRow::new(self.num.sign, self.den.clone()),
+            self.num.mag.clone(),
+        )
+    }
+
+    /// Returns `-1` for negative number, `0` for nought, `1` for positive number.
+    pub fn signum(&self) -> i8 {
+        self.num.signum()
+    }
+
+    /// `true` if and only if `Ratio` is integer.
+    pub fn is_integer(&self) -> bool {
+        self.den.is_unity()
+    }
+
+    /// `true` if and only if `Ratio` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.num.is_nought()
+    }
+
+    /// Returns greatest integer lesser than or equal to `Ratio`.
+    pub fn floor(&self) -> SignedPlacesRow {
+        divrem_euclid(&self.num, &SRow::from(self.den.clone()))
+            .unwrap()
+            .0
+    }
+
+    /// Returns least integer greater than or equal to `Ratio`.
+    pub fn ceil(&self) -> SignedPlacesRow {
+        self.neg().floor().neg()
+    }
+
+    /// Returns integer part of `Ratio`, rounded toward nought.
+    pub fn trunc(&self) -> SignedPlacesRow {
+        let ratio = divrem(&self.num.mag, &self.den).unwrap().0;
+        SRow::new(self.num.sign, ratio)
+    }
+
+    /// Returns nearest integer. Half-way cases are rounded away from nought.
+    pub fn round(&self) -> SignedPlacesRow {
+        let (ratio, rem) = divrem(&self.num.mag, &self.den).unwrap();
+
+        let ratio = match rel(&add(&rem, &rem), &self.den) {
+            Rel::Lesser(_) => ratio,
+            _ => add(&ratio, &Row::unity()),
+        };
+
+        SRow::new(self.num.sign, ratio)
+    }
+}
+
+impl core::fmt::Display for Ratio {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+impl From<SignedPlacesRow> for Ratio {
+    /// Converts integer `value` into `Ratio`.
+    fn from(value: SignedPlacesRow) -> Self {
+        Ratio {
+            num: value,
+            den: Row::unity(),
+        }
+    }
+}
+
+impl From<PlacesRow> for Ratio {
+    /// Converts integer `value` into `Ratio`.
+    fn from(value: PlacesRow) -> Self {
+        Ratio::from(SRow::from(value))
+    }
+}
+
+/// Checks relation of `num` to `comparand`.
+///
+/// Decimal difference information is not available, `Rel` holds `None` always.
+///
+/// Returns `Rel` relation.
+pub fn rel_ratio(num: &Ratio, comparand: &Ratio) -> Rel {
+    // a ÷b ⪒ c ÷d ⟺ a ⋅d ⪒ c ⋅b
+    let lhs = mul_signed(&num.num, &SRow::from(comparand.den.clone()));
+    let rhs = mul_signed(&comparand.num, &SRow::from(num.den.clone()));
+
+    match rel_signed(&lhs, &rhs) {
+        Rel::Greater(_) => Rel::Greater(None),
+        Rel::Lesser(_) => Rel::Lesser(None),
+        Rel::Equal => Rel::Equal,
+    }
+}
+
+/// Computes `addend1` and `addend2` sum.
+///
+/// Returns `Ratio` with result.
+pub fn add_ratio(addend1: &Ratio, addend2: &Ratio) -> Ratio {
+    // a ÷b +c ÷d = (a ⋅d +c ⋅b) ÷b ⋅d
+    let lhs = mul_signed(&addend1.num, &SRow::from(addend2.den.clone()));
+    let rhs = mul_signed(&addend2.num, &SRow::from(addend1.den.clone()));
+
+    Ratio::new(add_signed(&lhs, &rhs), mul(&addend1.den, &addend2.den)).unwrap()
+}
+
+/// Computes `minuend` and `subtrahend` difference.
+///
+/// Returns `Ratio` with result.
+pub fn sub_ratio(minuend: &Ratio, subtrahend: &Ratio) -> Ratio {
+    add_ratio(minuend, &subtrahend.neg())
+}
+
+/// Computes `factor1` and `factor2` product.
+///
+/// Returns `Ratio` with result.
+pub fn mul_ratio(factor1: &Ratio, factor2: &Ratio) -> Ratio {
+    let num = mul_signed(&factor1.num, &factor2.num);
+    Ratio::new(num, mul(&factor1.den, &factor2.den)).unwrap()
+}
+
+/// Computes `dividend` and `divisor` ratio.
+///
+/// Returns `Ratio` with result or `None` when `divisor` is nought.
+pub fn div_ratio(dividend: &Ratio, divisor: &Ratio) -> Option<Ratio> {
+    Some(mul_ratio(dividend, &divisor.recip()?))
+}
+
+/// Computes power `exp` of `base`. Negative `exp` yields power of reciprocal.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `Ratio` with result or `None` when `base` is nought and `exp` is negative.
+pub fn pow_ratio(base: &Ratio, exp: i32) -> Option<Ratio> {
+    let base = if exp < 0 { base.recip()? } else { base.clone() };
+    let exp = exp.unsigned_abs() as u64;
+
+    let sign = if exp.is_multiple_of(2) {
+        Sign::Plus
+    } else {
+        base.num.sign
+    };
+
+    // reduced base gives reduced power
+    Some(Ratio {
+        num: SRow::new(sign, pow_u64(&base.num.mag, exp)),
+        den: pow_u64(&base.den, exp),
+    })
+}
+
+/// Rounding mode enumeration.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rounding {
+    /// Away from nought.
+    Up,
+    /// Toward nought, truncation.
+    Down,
+    /// Toward positive infinity.
+    Ceiling,
+    /// Toward negative infinity.
+    Floor,
+    /// To nearest, half-way cases away from nought.
+    HalfUp,
+    /// To nearest, half-way cases toward nought.
+    HalfDown,
+    /// To nearest, half-way cases to even neighbour. Also known as banker's rounding.
+    HalfEven,
+}
+
+/// Computes magnitude of `num` ÷`den` rounded according to `rounding`.
+///
+/// `sign` is sign of ratio, required by directed rounding modes.
+fn round_ratio(sign: Sign, num: &Row, den: &Row, rounding: Rounding) -> Row {
+    let (ratio, rem) = divrem(num, den).unwrap();
+    if rem.is_nought() {
+        return ratio;
+    }
+
+    let increment = match rounding {
+        Rounding::Up => true,
+        Rounding::Down => false,
+        Rounding::Ceiling => sign == Sign::Plus,
+        Rounding::Floor => sign == Sign::Minus,
+        _ => match rel(&add(&rem, &rem), den) {
+            Rel::Greater(_) => true,
+            Rel::Lesser(_) => false,
+            Rel::Equal => match rounding {
+                Rounding::HalfUp => true,
+                Rounding::HalfDown => false,
+                _ => ratio.row[0] % 2 == 1,
+            },
+        },
+    };
+
+    if increment {
+        add(&ratio, &Row::unity())
+    } else {
+        ratio
+    }
+}
+
+/// `BigDecimal` represents decimal number as `SignedPlacesRow` coefficient and scale.
+///
+/// Value is coefficient ⋅10⁻ˢᶜᵃˡᵉ. Scale is count of fractional places, negative scale
+/// stands for trailing zeros of integer. Trailing fractional zeros are kept, thus
+/// `"1.50"` and `"1.5"` are equal by value but not by representation. Check with
+/// `fn rel_decimal`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BigDecimal {
+    coef: SignedPlacesRow,
+    scale: i32,
+}
+
+impl BigDecimal {
+    /// Ctor from coefficient `coef` and `scale`.
+    pub fn new(coef: SignedPlacesRow, scale: i32) -> Self {
+        BigDecimal { coef, scale }
+    }
+
+    /// Handy ctor for usage with long numbers.
+    ///
+    /// `s` can be integer like `"-12"` or decimal like `"-1234.5600"`, optionally started
+    /// with `-` or `+` sign. Fractional places count is kept as scale.
+    ///
+    /// Returns `BigDecimal` or index in `s` where uncovertable `char` was encountered.
+    /// `None` for empty string, missing digits or fractional places count exceeding
+    /// `i32::MAX`.
+    pub fn new_from_str(s: &str) -> Result<Self, Option<usize>> {
+        let (coef, places) = decimal_split(s)?;
+        let scale = i32::try_from(places).map_err(|_| None)?;
+        Ok(BigDecimal::new(coef, scale))
+    }
+
+    /// Returns `String` representation like `"-1234.5600"`.
+    ///
+    /// Fractional places count equals to positive scale. Negative scale is written
+    /// as trailing zeros.
+    pub fn to_number(&self) -> String {
+        let mut number = String::new();
+        if self.coef.sign == Sign::Minus {
+            number.push('-');
+        }
+
+        let digits = self.coef.mag.to_number();
+        if self.scale <= 0 {
+            number.push_str(&digits);
+            if !self.coef.is_nought() {
+                number.extend((0..self.scale.unsigned_abs()).map(|_| '0'));
+            }
+
+            return number;
+        }
+
+        let scale = self.scale as usize;
+        if digits.len() <= scale {
+            number.push_str("0.");
+            number.extend((digits.len()..scale).map(|_| '0'));
+            number.push_str(&digits);
+        } else {
+            let point = digits.len() - scale;
+            number.push_str(&digits[..point]);
+            number.push('.');
+            number.push_str(&digits[point..]);
+        }
+
+        number
+    }
+
+    /// Returns coefficient.
+    pub fn coef(&self) -> &SignedPlacesRow {
+        &self.coef
+    }
+
+    /// Returns scale.
+    pub fn scale(&self) -> i32 {
+        self.scale
+    }
+
+    /// Returns absolute value.
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal::new(self.coef.abs(), self.scale)
+    }
+
+    /// Returns additive inverse.
+    pub fn neg(&self) -> BigDecimal {
+        BigDecimal::new(self.coef.neg(), self.scale)
+    }
+
+    /// Returns `-1` for negative number, `0` for nought, `1` for positive number.
+    pub fn signum(&self) -> i8 {
+        self.coef.signum()
+    }
+
+    /// `true` if and only if `BigDecimal` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.coef.is_nought()
+    }
+
+    /// Changes scale to `scale`, rounding according to `rounding` when scale decreases.
+    ///
+    /// Returns `BigDecimal` with result.
+    pub fn rescale(&self, scale: i32, rounding: Rounding) -> BigDecimal {
+        let diff = scale as i64 - self.scale as i64;
+        let mag = if diff >= 0 {
+            shift_places(&self.coef.mag, diff as usize)
+        } else {
+            let den = ten_power(diff.unsigned_abs() as usize);
+            round_ratio(self.coef.sign, &self.coef.mag, &den, rounding)
+        };
+
+        BigDecimal::new(SRow::new(self.coef.sign, mag), scale)
+    }
+
+    /// Removes trailing zeros of coefficient, scale is decreased accordingly.
+    ///
+    /// Zeros which would decrease scale below `i32::MIN` are kept.
+    ///
+    /// Returns `BigDecimal` with result. Nought is of scale `0`.
+    pub fn normalize(&self) -> BigDecimal {
+        if self.coef.is_nought() {
+            return BigDecimal::new(SRow::nought(), 0);
+        }
+
+        // scale cannot decrease below i32::MIN
+        let row = &self.coef.mag.row;
+        let bound = self.scale.abs_diff(i32::MIN) as usize;
+        let zeros = row.iter().take_while(|&&d| d == 0).count().min(bound);
+        let mag = Row {
+            row: row[zeros..].to_vec(),
+        };
+
+        let scale = (self.scale as i64 - zeros as i64) as i32;
+        BigDecimal::new(SRow::new(self.coef.sign, mag), scale)
+    }
+
+    /// Converts `BigDecimal` into exact `Ratio`.
+    pub fn to_ratio(&self) -> Ratio {
+        let scale = self.scale.unsigned_abs() as usize;
+        if self.scale >= 0 {
+            Ratio::new(self.coef.clone(), ten_power(scale)).unwrap()
+        } else {
+            let mag = shift_places(&self.coef.mag, scale);
+            Ratio::from(SRow::new(self.coef.sign, mag))
+        }
+    }
+}
+
+impl core::fmt::Display for BigDecimal {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+impl From<SignedPlacesRow> for BigDecimal {
+    /// Converts integer `value` into `BigDecimal` of scale `0`.
+    fn from(value: SignedPlacesRow) -> Self {
+        BigDecimal::new(value, 0)
+    }
+}
+
+/// Aligns coefficients of `dec1` and `dec2` to greater scale.
+fn align_decimals(dec1: &BigDecimal, dec2: &BigDecimal) -> (SRow, SRow, i32) {
+    let scale = dec1.scale.max(dec2.scale);
+    let align = |d: &BigDecimal| {
+        let mag = shift_places(&d.coef.mag, scale.abs_diff(d.scale) as usize);
+        SRow::new(d.coef.sign, mag)
+    };
+
+    (align(dec1), align(dec2), scale)
+}
+
+/// Checks relation of `num` to `comparand` by value.
+///
+/// Decimal difference information is not available, `Rel` holds `None` always.
+///
+/// Returns `Rel` relation.
+pub fn rel_decimal(num: &BigDecimal, comparand: &BigDecimal) -> Rel {
+    let (coef1, coef2, _) = align_decimals(num, comparand);
+
+    match rel_signed(&coef1, &coef2) {
+        Rel::Greater(_) => Rel::Greater(None),
+        Rel::Lesser(_) => Rel::Lesser(None),
+        Rel::Equal => Rel::Equal,
+    }
+}
+
+/// Computes `addend1` and `addend2` sum exactly.
+///
+/// Returns `BigDecimal` with result of greater scale of addends.
+pub fn add_decimal(addend1: &BigDecimal, addend2: &BigDecimal) -> BigDecimal {
+    let (coef1, coef2, scale) = align_decimals(addend1, addend2);
+    BigDecimal::new(add_signed(&coef1, &coef2), scale)
+}
+
+/// Computes `minuend` and `subtrahend` difference exactly.
+///
+/// Returns `BigDecimal` with result of greater scale of operands.
+pub fn sub_decimal(minuend: &BigDecimal, subtrahend: &BigDecimal) -> BigDecimal {
+    add_decimal(minuend, &subtrahend.neg())
+}
+
+/// Computes `factor1` and `factor2` product exactly.
+///
+/// Returns `BigDecimal` with result of scale equal to sum of scales of factors or `None`
+/// when this sum does not fit into `i32`.
+pub fn mul_decimal(factor1: &BigDecimal, factor2: &BigDecimal) -> Option<BigDecimal> {
+    let scale = factor1.scale.checked_add(factor2.scale)?;
+    let coef = mul_signed(&factor1.coef, &factor2.coef);
+    Some(BigDecimal::new(coef, scale))
+}
+
+/// Computes `dividend` and `divisor` ratio to `scale` fractional places.
+///
+/// Ratio is rounded according to `rounding`.
+///
+/// Returns `BigDecimal` with result or `None` when `divisor` is nought.
+pub fn div_decimal(
+    dividend: &BigDecimal,
+    divisor: &BigDecimal,
+    scale: i32,
+    rounding: Rounding,
+) -> Option<BigDecimal> {
+    if divisor.is_nought() {
+        return None;
+    }
+
+    // (a ⋅10⁻ˢᵃ) ÷(b ⋅10⁻ˢᵇ) = (a ⋅10ᵏ ÷b) ⋅10⁻ˢᶜᵃˡᵉ, k = scale -sa +sb
+    let k = scale as i64 - dividend.scale as i64 + divisor.scale as i64;
+    let shift = k.unsigned_abs() as usize;
+    let (num, den) = if k >= 0 {
+        (
+            shift_places(&dividend.coef.mag, shift),
+            divisor.coef.mag.clone(),
+        )
+    } else {
+        (
+            dividend.coef.mag.clone(),
+            shift_places(&divisor.coef.mag, shift),
+        )
+    };
+
+    let sign = dividend.coef.sign.product(divisor.coef.sign);
+    let mag = round_ratio(sign, &num, &den, rounding);
+
+    Some(BigDecimal::new(SRow::new(sign, mag), scale))
+}
+
+/// `Interval` represents closed interval [lo, hi] with `BigDecimal` bounds.
+///
+/// Operations round bounds outward, thus result interval always encloses exact result.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Interval {
+    lo: BigDecimal,
+    hi: BigDecimal,
+}
+
+impl Interval {
+    /// Ctor from lower bound `lo` and upper bound `hi`.
+    ///
+    /// Returns `Interval` or `None` when `lo` is greater than `hi`.
+    pub fn new(lo: BigDecimal, hi: BigDecimal) -> Option<Self> {
+        match rel_decimal(&lo, &hi) {
+            Rel::Greater(_) => None,
+            _ => Some(Interval { lo, hi }),
+        }
+    }
+
+    /// Ctor of degenerate interval [`value`, `value`].
+    pub fn point(value: BigDecimal) -> Self {
+        Interval {
+            lo: value.clone(),
+            hi: value,
+        }
+    }
+
+    /// Returns `String` representation like `"[-1.5, 2.25]"`.
+    pub fn to_number(&self) -> String {
+        let mut number = String::from("[");
+        number.push_str(&self.lo.to_number());
+        number.push_str(", ");
+        number.push_str(&self.hi.to_number());
+        number.push(']');
+        number
+    }
+
+    /// Returns lower bound.
+    pub fn lo(&self) -> &BigDecimal {
+        &self.lo
+    }
+
+    /// Returns upper bound.
+    pub fn hi(&self) -> &BigDecimal {
+        &self.hi
+    }
+
+    /// Computes width hi -lo exactly.
+    pub fn width(&self) -> BigDecimal {
+        sub_decimal(&self.hi, &self.lo)
+    }
+
+    /// `true` if and only if `value` lies in `Interval`.
+    pub fn contains(&self, value: &BigDecimal) -> bool {
+        !matches!(rel_decimal(&self.lo, value), Rel::Greater(_))
+            && !matches!(rel_decimal(value, &self.hi), Rel::Greater(_))
+    }
+
+    /// `true` if and only if `other` is subset of `Interval`.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.contains(&other.lo) && self.contains(&other.hi)
+    }
+}
+
+impl core::fmt::Display for Interval {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+/// Rounds `lo` downward and `hi` upward when their scale exceeds `scale`.
+fn interval_round(lo: BigDecimal, hi: BigDecimal, scale: i32) -> Interval {
+    let round = |d: BigDecimal, rounding| {
+        if d.scale > scale {
+            d.rescale(scale, rounding)
+        } else {
+            d
+        }
+    };
+
+    Interval {
+        lo: round(lo, Rounding::Floor),
+        hi: round(hi, Rounding::Ceiling),
+    }
+}
+
+/// Picks least and greatest of `values`.
+fn decimal_bounds(values: Vec<BigDecimal>) -> (BigDecimal, BigDecimal) {
+    let mut lo = values[0].clone();
+    let mut hi = values[0].clone();
+
+    for v in values.into_iter().skip(1) {
+        if let Rel::Lesser(_) = rel_decimal(&v, &lo) {
+            lo = v.clone();
+        }
+
+        if let Rel::Greater(_) = rel_decimal(&v, &hi) {
+            hi = v;
+        }
+    }
+
+    (lo, hi)
+}
+
+/// Computes intersection of `interval1` and `interval2`.
+///
+/// Returns `Interval` with result or `None` when intervals are disjoint.
+pub fn intersect_interval(interval1: &Interval, interval2: &Interval) -> Option<Interval> {
+    let (_, lo) = decimal_bounds(vec![interval1.lo.clone(), interval2.lo.clone()]);
+    let (hi, _) = decimal_bounds(vec![interval1.hi.clone(), interval2.hi.clone()]);
+
+    Interval::new(lo, hi)
+}
+
+/// Computes `addend1` and `addend2` sum.
+///
+/// Bounds of greater scale than `scale` are rounded outward to `scale`.
+///
+/// Returns `Interval` with result.
+pub fn add_interval(addend1: &Interval, addend2: &Interval, scale: i32) -> Interval {
+    let lo = add_decimal(&addend1.lo, &addend2.lo);
+    let hi = add_decimal(&addend1.hi, &addend2.hi);
+
+    interval_round(lo, hi, scale)
+}
+
+/// Computes `minuend` and `subtrahend` difference.
+///
+/// Bounds of greater scale than `scale` are rounded outward to `scale`.
+///
+/// Returns `Interval` with result.
+pub fn sub_interval(minuend: &Interval, subtrahend: &Interval, scale: i32) -> Interval {
+    let lo = sub_decimal(&minuend.lo, &subtrahend.hi);
+    let hi = sub_decimal(&minuend.hi, &subtrahend.lo);
+
+    interval_round(lo, hi, scale)
+}
+
+/// Computes `factor1` and `factor2` product.
+///
+/// Bounds of greater scale than `scale` are rounded outward to `scale`.
+///
+/// Returns `Interval` with result or `None` when scale of bounds product does not fit
+/// into `i32`.
+pub fn mul_interval(factor1: &Interval, factor2: &Interval, scale: i32) -> Option<Interval> {
+    let mut products = Vec::new();
+    for a in [&factor1.lo, &factor1.hi] {
+        for b in [&factor2.lo, &factor2.hi] {
+            products.push(mul_decimal(a, b)?);
+        }
+    }
+
+    let (lo, hi) = decimal_bounds(products);
+    Some(interval_round(lo, hi, scale))
+}
+
+/// Computes `dividend` and `divisor` ratio.
+///
+/// Bounds are computed to `scale` fractional places, rounded outward.
+///
+/// Returns `Interval` with result or `None` when `divisor` contains nought.
+pub fn div_interval(dividend: &Interval, divisor: &Interval, scale: i32) -> Option<Interval> {
+    if divisor.contains(&BigDecimal::new(SRow::nought(), 0)) {
+        return None;
+    }
+
+    let mut floors = Vec::new();
+    let mut ceilings = Vec::new();
+    for a in [&dividend.lo, &dividend.hi] {
+        for b in [&divisor.lo, &divisor.hi] {
+            floors.push(div_decimal(a, b, scale, Rounding::Floor).unwrap());
+            ceilings.push(div_decimal(a, b, scale, Rounding::Ceiling).unwrap());
+        }
+    }
+
+    let (lo, _) = decimal_bounds(floors);
+    let (_, hi) = decimal_bounds(ceilings);
+
+    Some(Interval { lo, hi })
+}
+
+/// Computes `dividend` and `divisor` ratio to `places` fractional places.
+///
+/// Long division continues into fractional places, last place is rounded according
+/// to `rounding`.
+///
+/// Returns `BigDecimal` of scale `places` with result or `None` when `divisor` is nought
+/// or `places` exceeds `i32::MAX`.
+pub fn div_to_decimal(
+    dividend: &PlacesRow,
+    divisor: &PlacesRow,
+    places: u32,
+    rounding: Rounding,
+) -> Option<BigDecimal> {
+    if divisor.is_nought() {
+        return None;
+    }
+
+    let scale = i32::try_from(places).ok()?;
+    let num = shift_places(dividend, places as usize);
+    let coef = round_ratio(Sign::Plus, &num, divisor, rounding);
+
+    Some(BigDecimal::new(SRow::from(coef), scale))
+}
+
+/// Computes `dividend` and `divisor` ratio to `places` fractional places, e.g.
+/// 22 ÷7 to 6 places is `"3.142857"`.
+///
+/// Check with `fn div_to_decimal`.
+///
+/// Returns `String` with result or `None` when `divisor` is nought or `places` exceeds
+/// `i32::MAX`.
+pub fn div_to_places(
+    dividend: &PlacesRow,
+    divisor: &PlacesRow,
+    places: u32,
+    rounding: Rounding,
+) -> Option<String> {
+    div_to_decimal(dividend, divisor, places, rounding).map(|dec| dec.to_number())
+}
+
+/// Decimal expansion of fraction, check with `fn decimal_expansion`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DecimalExpansion {
+    integer: PlacesRow,
+    non_repeating: String,
+    repeating: String,
+}
+
+impl DecimalExpansion {
+    /// Returns integer part.
+    pub fn integer(&self) -> &PlacesRow {
+        &self.integer
+    }
+
+    /// Returns fractional places before period.
+    pub fn non_repeating(&self) -> &str {
+        &self.non_repeating
+    }
+
+    /// Returns period of fractional places. Empty for terminating expansion.
+    pub fn repeating(&self) -> &str {
+        &self.repeating
+    }
+
+    /// Returns `String` representation with period in parentheses like `"0.1(6)"`.
+    pub fn to_number(&self) -> String {
+        let mut number = self.integer.to_number();
+        if self.non_repeating.is_empty() && self.repeating.is_empty() {
+            return number;
+        }
+
+        number.push('.');
+        number.push_str(&self.non_repeating);
+        if !self.repeating.is_empty() {
+            number.push('(');
+            number.push_str(&self.repeating);
+            number.push(')');
+        }
+
+        number
+    }
+}
+
+impl core::fmt::Display for DecimalExpansion {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+/// Computes decimal expansion of `num` ÷`den` with period detection.
+///
+/// For reduced den = 2ᵃ ⋅5ᵇ ⋅m, gcd(m, 10) = 1, places before period count is
+/// max(a, b) and period length is multiplicative order of 10 modulo m.
+///
+/// Potentially CPU, memory intesive since period can be as long as m -1 places.
+///
+/// Returns `DecimalExpansion` or `None` when `den` is nought or period length exceeds
+/// `usize`.
+pub fn decimal_expansion(num: &PlacesRow, den: &PlacesRow) -> Option<DecimalExpansion> {
+    if den.is_nought() {
+        return None;
+    }
+
+    let divisor = gcd(num, den);
+    let num = divrem(num, &divisor).unwrap().0;
+    let den = divrem(den, &divisor).unwrap().0;
+    let (integer, rem) = divrem(&num, &den).unwrap();
+
+    let mut m = den.clone();
+    let mut twos = 0;
+    let mut fives = 0;
+    loop {
+        let (ratio, rem) = divrem_num_raw(&m.row, 2);
+        if rem != 0 {
+            break;
+        }
+        m = Row { row: ratio };
+        twos += 1;
+    }
+    loop {
+        let (ratio, rem) = divrem_num_raw(&m.row, 5);
+        if rem != 0 {
+            break;
+        }
+        m = Row { row: ratio };
+        fives += 1;
+    }
+
+    let pre_len = twos.max(fives);
+    let period_len = if m.is_unity() {
+        0
+    } else {
+        let order = carmichael(&factorize(&m));
+        let factors = factorize(&order);
+        let (period, _) = mul_order(&Row::new_from_num(10), &m, order, factors);
+        usize::try_from(to_num_raw(&period.row)?).ok()?
+    };
+
+    // places of rem ⋅10ᵖʳᵉ⁺ᵖᵉʳⁱᵒᵈ ÷den
+    let len = pre_len + period_len;
+    let places = divrem(&shift_places(&rem, len), &den).unwrap().0;
+    let mut digits = String::new();
+    if len > 0 {
+        let places = places.to_number();
+        digits.extend((places.len()..len).map(|_| '0'));
+        digits.push_str(&places);
+    }
+
+    Some(DecimalExpansion {
+        integer,
+        non_repeating: digits[..pre_len].into(),
+        repeating: digits[pre_len..].into(),
+    })
+}
+
+/// Floating point context.
+///
+/// Describes precision in significant decimal places and rounding mode of
+/// floating point operations.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FloatContext {
+    prec: usize,
+    rounding: Rounding,
+}
+
+impl FloatContext {
+    /// Ctor from precision `prec` in significant places and `rounding` mode.
+    ///
+    /// Returns `FloatContext` or `None` when `prec` is `0`.
+    pub fn new(prec: usize, rounding: Rounding) -> Option<Self> {
+        if prec == 0 {
+            None
+        } else {
+            Some(FloatContext { prec, rounding })
+        }
+    }
+
+    /// Returns precision in significant places.
+    pub fn prec(&self) -> usize {
+        self.prec
+    }
+
+    /// Returns rounding mode.
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+}
+
+/// `BigFloat` represents decimal floating point number as `SignedPlacesRow` mantissa
+/// and exponent.
+///
+/// Value is mantissa ⋅10ᵉˣᵖ. Mantissa is kept without trailing zeros, nought is of
+/// exponent `0`, thus each value has unique representation. Special values like
+/// infinity or NaN are not supported, operations return `None` instead.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BigFloat {
+    mant: SignedPlacesRow,
+    exp: i64,
+}
+
+impl BigFloat {
+    /// Ctor from mantissa `mant` and exponent `exp`.
+    ///
+    /// Value is exact, trailing zeros of `mant` are moved into exponent.
+    ///
+    /// Returns `BigFloat` or `None` when exponent exceeds `i64` range.
+    pub fn new(mant: SignedPlacesRow, exp: i64) -> Option<Self> {
+        if mant.is_nought() {
+            return Some(BigFloat {
+                mant: SRow::nought(),
+                exp: 0,
+            });
+        }
+
+        let row = &mant.mag.row;
+        let zeros = row.iter().take_while(|&&d| d == 0).count();
+        if zeros == 0 {
+            return Some(BigFloat { mant, exp });
+        }
+
+        let exp = exp.checked_add(i64::try_from(zeros).ok()?)?;
+        let mag = Row {
+            row: row[zeros..].to_vec(),
+        };
+
+        Some(BigFloat {
+            mant: SRow::new(mant.sign, mag),
+            exp,
+        })
+    }
+
+    /// Handy ctor for usage with long numbers.
+    ///
+    /// `s` can be decimal like `"-12.5"`, optionally followed by exponent like
+    /// `"-1.25e1"` or `"125E-1"`. Value is exact, check with `fn round`.
+    ///
+    /// Returns `BigFloat` or index in `s` where uncovertable `char` was encountered.
+    /// `None` for empty string, missing digits or exponent out of `i64` range.
+    pub fn new_from_str(s: &str) -> Result<Self, Option<usize>> {
+        let (dec, exp) = match s.find(['e', 'E']) {
+            Some(inx) => {
+                let exp = &s[inx + 1..];
+                let (sign, digits) = sign_split(exp);
+
+                let offset = inx + 1 + exp.len() - digits.len();
+                if let Some(e) = digits.find(|c: char| !c.is_ascii_digit()) {
+                    return Err(Some(offset + e));
+                }
+
+                let exp = match sign {
+                    Sign::Plus => digits.parse::<i64>(),
+                    Sign::Minus => exp.parse::<i64>(),
+                };
+
+                (&s[..inx], exp.map_err(|_| None)?)
+            }
+            None => (s, 0),
+        };
+
+        let (mant, places) = decimal_split(dec)?;
+        let exp = i64::try_from(places).ok().and_then(|p| exp.checked_sub(p));
+
+        exp.and_then(|exp| BigFloat::new(mant, exp)).ok_or(None)
+    }
+
+    /// Returns `String` representation in scientific notation like `"-1.25e-7"`.
+    pub fn to_number(&self) -> String {
+        let digits = self.mant.mag.to_number();
+
+        let mut number = String::new();
+        if self.mant.sign == Sign::Minus {
+            number.push('-');
+        }
+
+        number.push_str(&digits[..1]);
+        if digits.len() > 1 {
+            number.push('.');
+            number.push_str(&digits[1..]);
+        }
+
+        let exp = self.exp as i128 + digits.len() as i128 - 1;
+        number.push_str(&alloc::format!("e{exp}"));
+
+        number
+    }
+
+    /// Returns mantissa.
+    pub fn mant(&self) -> &SignedPlacesRow {
+        &self.mant
+    }
+
+    /// Returns exponent.
+    pub fn exp(&self) -> i64 {
+        self.exp
+    }
+
+    /// Returns absolute value.
+    pub fn abs(&self) -> BigFloat {
+        BigFloat {
+            mant: self.mant.abs(),
+            exp: self.exp,
+        }
+    }
+
+    /// Returns additive inverse.
+    pub fn neg(&self) -> BigFloat {
+        BigFloat {
+            mant: self.mant.neg(),
+            exp: self.exp,
+        }
+    }
+
+    /// Returns `-1` for negative number, `0` for nought, `1` for positive number.
+    pub fn signum(&self) -> i8 {
+        self.mant.signum()
+    }
+
+    /// `true` if and only if `BigFloat` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.mant.is_nought()
+    }
+
+    /// Rounds `BigFloat` to precision and rounding mode of `ctx`.
+    ///
+    /// Returns `BigFloat` with result or `None` when exponent exceeds `i64` range.
+    pub fn round(&self, ctx: &FloatContext) -> Option<BigFloat> {
+        float_round(self.mant.sign, &self.mant.mag, &Row::unity(), self.exp, ctx)
+    }
+
+    /// Converts `BigFloat` into exact `BigDecimal`.
+    ///
+    /// Returns `BigDecimal` with result or `None` when exponent does not fit into scale.
+    pub fn to_decimal(&self) -> Option<BigDecimal> {
+        let scale = i32::try_from(self.exp.checked_neg()?).ok()?;
+        Some(BigDecimal::new(self.mant.clone(), scale))
+    }
+}
+
+impl core::fmt::Display for BigFloat {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+impl From<BigDecimal> for BigFloat {
+    /// Converts `value` into exact `BigFloat`.
+    fn from(value: BigDecimal) -> Self {
+        // `i32` scale leaves exponent enough room for any count of trailing zeros
+        BigFloat::new(value.coef, -(value.scale as i64)).unwrap()
+    }
+}
+
+/// Rounds `num` ÷`den` to `ctx` precision.
+///
+/// Quotient is computed to precision places exactly at once and rounded then,
+/// thus result is correctly rounded.
+///
+/// Returns `BigFloat` of value rounded `num` ÷`den` ⋅10ᵉˣᵖ or `None` when exponent
+/// exceeds `i64` range.
+fn float_round(sign: Sign, num: &Row, den: &Row, exp: i64, ctx: &FloatContext) -> Option<BigFloat> {
+    if num.is_nought() {
+        return BigFloat::new(SRow::nought(), 0);
+    }
+
+    let prec = ctx.prec;
+
+    // num ÷den ⋅10ᵏ has prec or prec +1 places
+    let mut k = prec as i64 - (num.len() as i64 - den.len() as i64);
+    let scaled = |k: i64| {
+        let shift = k.unsigned_abs() as usize;
+        if k >= 0 {
+            (shift_places(num, shift), den.clone())
+        } else {
+            (num.clone(), shift_places(den, shift))
+        }
+    };
+
+    let (mut n, mut d) = scaled(k);
+    if !matches!(rel(&n, &shift_places(&d, prec)), Rel::Lesser(_)) {
+        k -= 1;
+        (n, d) = scaled(k);
+    }
+
+    let mut mant = round_ratio(sign, &n, &d, ctx.rounding);
+
+    // carry into 10ᵖʳᵉᶜ
+    if mant.len() > prec {
+        mant = divrem(&mant, &Row::new_from_num(10)).unwrap().0;
+        k -= 1;
+    }
+
+    BigFloat::new(SRow::new(sign, mant), exp.checked_sub(k)?)
+}
+
+/// Checks relation of `num` to `comparand`.
+///
+/// Decimal difference information is not available, `Rel` holds `None` always.
+///
+/// Returns `Rel` relation.
+pub fn rel_float(num: &BigFloat, comparand: &BigFloat) -> Rel {
+    let rel_mag = |a: &BigFloat, b: &BigFloat| {
+        // highest place position decides, when equal, aligned mantissas do
+        if a.is_nought() || b.is_nought() {
+            return rel(&a.mant.mag, &b.mant.mag);
+        }
+
+        let top_a = a.exp as i128 + a.mant.mag.len() as i128;
+        let top_b = b.exp as i128 + b.mant.mag.len() as i128;
+
+        match top_a.cmp(&top_b) {
+            Ordering::Greater => Rel::Greater(None),
+            Ordering::Less => Rel::Lesser(None),
+            Ordering::Equal => {
+                let exp = a.exp.min(b.exp);
+                let a = shift_places(&a.mant.mag, a.exp.abs_diff(exp) as usize);
+                let b = shift_places(&b.mant.mag, b.exp.abs_diff(exp) as usize);
+                rel(&a, &b)
+            }
+        }
+    };
+
+    let rel = match (num.mant.sign, comparand.mant.sign) {
+        (Sign::Plus, Sign::Plus) => rel_mag(num, comparand),
+        (Sign::Minus, Sign::Minus) => rel_mag(comparand, num),
+        (Sign::Plus, Sign::Minus) => Rel::Greater(None),
+        (Sign::Minus, Sign::Plus) => Rel::Lesser(None),
+    };
+
+    match rel {
+        Rel::Greater(_) => Rel::Greater(None),
+        Rel::Lesser(_) => Rel::Lesser(None),
+        Rel::Equal => Rel::Equal,
+    }
+}
+
+/// Computes `addend1` and `addend2` sum correctly rounded to `ctx`.
+///
+/// Returns `BigFloat` with result or `None` when exponent exceeds `i64` range.
+pub fn add_float(addend1: &BigFloat, addend2: &BigFloat, ctx: &FloatContext) -> Option<BigFloat> {
+    if addend1.is_nought() || addend2.is_nought() {
+        let addend = if addend1.is_nought() {
+            addend2
+        } else {
+            addend1
+        };
+        return addend.round(ctx);
+    }
+
+    let top = |f: &BigFloat| f.exp as i128 + f.mant.mag.len() as i128;
+    let (big, small) = if top(addend1) >= top(addend2) {
+        (addend1, addend2)
+    } else {
+        (addend2, addend1)
+    };
+
+    // addend entirely below rounding position matters only by its sign, it is
+    // replaced with unity below all places of sum, exponent bound -1 of unity is not
+    // below exponent of replaced addend
+    let bound = (big.exp as i128).min(top(big) - ctx.prec as i128 - 3);
+    let small = if top(small) <= bound {
+        BigFloat {
+            mant: SRow::new(small.mant.sign, Row::unity()),
+            exp: (bound - 1) as i64,
+        }
+    } else {
+        small.clone()
+    };
+
+    let exp = big.exp.min(small.exp);
+    let align = |f: &BigFloat| {
+        let mag = shift_places(&f.mant.mag, f.exp.abs_diff(exp) as usize);
+        SRow::new(f.mant.sign, mag)
+    };
+
+    let sum = add_signed(&align(big), &align(&small));
+    float_round(sum.sign, &sum.mag, &Row::unity(), exp, ctx)
+}
+
+/// Computes `minuend` and `subtrahend` difference correctly rounded to `ctx`.
+///
+/// Returns `BigFloat` with result or `None` when exponent exceeds `i64` range.
+pub fn sub_float(
+    minuend: &BigFloat,
+    subtrahend: &BigFloat,
+    ctx: &FloatContext,
+) -> Option<BigFloat> {
+    add_float(minuend, &subtrahend.neg(), ctx)
+}
+
+/// Computes `factor1` and `factor2` product correctly rounded to `ctx`.
+///
+/// Returns `BigFloat` with result or `None` when exponent exceeds `i64` range.
+pub fn mul_float(factor1: &BigFloat, factor2: &BigFloat, ctx: &FloatContext) -> Option<BigFloat> {
+    let mant = mul_signed(&factor1.mant, &factor2.mant);
+    let exp = factor1.exp.checked_add(factor2.exp)?;
+
+    float_round(mant.sign, &mant.mag, &Row::unity(), exp, ctx)
+}
+
+/// Computes `dividend` and `divisor` ratio correctly rounded to `ctx`.
+///
+/// Returns `BigFloat` with result or `None` when `divisor` is nought or exponent
+/// exceeds `i64` range.
+pub fn div_float(dividend: &BigFloat, divisor: &BigFloat, ctx: &FloatContext) -> Option<BigFloat> {
+    if divisor.is_nought() {
+        return None;
+    }
+
+    let sign = dividend.mant.sign.product(divisor.mant.sign);
+    let exp = dividend.exp.checked_sub(divisor.exp)?;
+
+    float_round(sign, &dividend.mant.mag, &divisor.mant.mag, exp, ctx)
+}
+
+/// Computes square root of `num` correctly rounded to `ctx`.
+///
+/// Returns `BigFloat` with result or `None` when `num` is negative.
+pub fn sqrt_float(num: &BigFloat, ctx: &FloatContext) -> Option<BigFloat> {
+    if num.mant.sign == Sign::Minus {
+        return None;
+    }
+
+    if num.is_nought() {
+        return Some(num.clone());
+    }
+
+    // n = m ⋅10ˢ has at least 2 ⋅(prec +1) places, e -s is even
+    let mag = &num.mant.mag;
+    let mut s = (2 * (ctx.prec + 1)).saturating_sub(mag.len()) as i128;
+    if (num.exp as i128 - s) % 2 != 0 {
+        s += 1;
+    }
+
+    let (root, rem) = isqrt(&shift_places(mag, s as usize));
+
+    // sticky place keeps rounding of inexact root
+    let sticky = if rem.is_nought() { 0 } else { 1 };
+    let root = add(&shift_places(&root, 1), &Row::new_from_num(sticky));
+
+    // halved exponent fits into i64 always
+    let exp = ((num.exp as i128 - s) / 2 - 1) as i64;
+    float_round(Sign::Plus, &root, &Row::unity(), exp, ctx)
+}
+
+/// `GaussianInt` represents Gaussian integer a +bi with `SignedPlacesRow` components.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GaussianInt {
+    re: SignedPlacesRow,
+    im: SignedPlacesRow,
+}
+
+type GInt = GaussianInt;
+
+impl GaussianInt {
+    /// Ctor from real part `re` and imaginary part `im`.
+    pub fn new(re: SignedPlacesRow, im: SignedPlacesRow) -> Self {
+        GInt { re, im }
+    }
+
+    /// Handy ctor for usage with _classic_ primitive numeric data type.
+    pub fn new_from_num(re: i128, im: i128) -> Self {
+        GInt::new(SRow::new_from_num(re), SRow::new_from_num(im))
+    }
+
+    /// Returns `String` representation like `"3-4i"`.
+    pub fn to_number(&self) -> String {
+        let mut number = self.re.to_number();
+        number.push(if self.im.sign == Sign::Minus {
+            '-'
+        } else {
+            '+'
+        });
+        number.push_str(&self.im.mag.to_number());
+        number.push('i');
+        number
+    }
+
+    /// Returns real part.
+    pub fn re(&self) -> &SignedPlacesRow {
+        &self.re
+    }
+
+    /// Returns imaginary part.
+    pub fn im(&self) -> &SignedPlacesRow {
+        &self.im
+    }
+
+    /// Computes norm N(a +bi) = a² +b².
+    pub fn norm(&self) -> PlacesRow {
+        add(
+            &mul(&self.re.mag, &self.re.mag),
+            &mul(&self.im.mag, &self.im.mag),
+        )
+    }
+
+    /// Returns complex conjugate a -bi.
+    pub fn conj(&self) -> GaussianInt {
+        GInt::new(self.re.clone(), self.im.neg())
+    }
+
+    /// Returns additive inverse.
+    pub fn neg(&self) -> GaussianInt {
+        GInt::new(self.re.neg(), self.im.neg())
+    }
+
+    /// `true` if and only if `GaussianInt` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.re.is_nought() && self.im.is_nought()
+    }
+
+    /// `true` if and only if `GaussianInt` is unit, one of 1, -1, i, -i.
+    pub fn is_unit(&self) -> bool {
+        self.norm().is_unity()
+    }
+
+    /// Returns associate in first quadrant, re > 0 ∧ im ≥ 0, or nought.
+    pub fn normalize(&self) -> GaussianInt {
+        let mut z = self.clone();
+        // multiplication by i rotates quadrants, (a +bi) ⋅i = -b +ai
+        for _ in 0..4 {
+            if z.is_nought() || (z.re.signum() > 0 && z.im.signum() >= 0) {
+                break;
+            }
+
+            z = GInt::new(z.im.neg(), z.re);
+        }
+
+        z
+    }
+}
+
+impl core::fmt::Display for GaussianInt {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+/// Computes `addend1` and `addend2` sum.
+///
+/// Returns `GaussianInt` with result.
+pub fn add_gaussian(addend1: &GaussianInt, addend2: &GaussianInt) -> GaussianInt {
+    GInt::new(
+        add_signed(&addend1.re, &addend2.re),
+        add_signed(&addend1.im, &addend2.im),
+    )
+}
+
+/// Computes `minuend` and `subtrahend` difference.
+///
+/// Returns `GaussianInt` with result.
+pub fn sub_gaussian(minuend: &GaussianInt, subtrahend: &GaussianInt) -> GaussianInt {
+    add_gaussian(minuend, &subtrahend.neg())
+}
+
+/// Computes `factor1` and `factor2` product.
+///
+/// Returns `GaussianInt` with result.
+pub fn mul_gaussian(factor1: &GaussianInt, factor2: &GaussianInt) -> GaussianInt {
+    // (a +bi) ⋅(c +di) = (ac -bd) +(ad +bc)i
+    let (a, b, c, d) = (&factor1.re, &factor1.im, &factor2.re, &factor2.im);
+    GInt::new(
+        sub_signed(&mul_signed(a, c), &mul_signed(b, d)),
+        add_signed(&mul_signed(a, d), &mul_signed(b, c)),
+    )
+}
+
+/// Computes `dividend` and `divisor` ratio and remainder using Euclidean division.
+///
+/// Ratio components are rounded to nearest, thus N(remainder) ≤ N(`divisor`) ÷2.
+///
+/// Returns tuple with `GaussianInt` ratio and `GaussianInt` remainder in order or `None`
+/// when `divisor` is nought.
+pub fn divrem_gaussian(
+    dividend: &GaussianInt,
+    divisor: &GaussianInt,
+) -> Option<(GaussianInt, GaussianInt)> {
+    if divisor.is_nought() {
+        return None;
+    }
+
+    // x ÷y = x ⋅ȳ ÷N(y)
+    let num = mul_gaussian(dividend, &divisor.conj());
+    let norm = divisor.norm();
+    let round = |c: &SRow| {
+        let mag = round_ratio(c.sign, &c.mag, &norm, Rounding::HalfUp);
+        SRow::new(c.sign, mag)
+    };
+
+    let ratio = GInt::new(round(&num.re), round(&num.im));
+    let rem = sub_gaussian(dividend, &mul_gaussian(&ratio, divisor));
+
+    Some((ratio, rem))
+}
+
+/// Computes greatest common divisor of `num1` and `num2` using Euclidean algorithm.
+///
+/// Returns `GaussianInt` associate in first quadrant. Greatest common divisor of noughts
+/// is nought.
+pub fn gcd_gaussian(num1: &GaussianInt, num2: &GaussianInt) -> GaussianInt {
+    let mut a = num1.clone();
+    let mut b = num2.clone();
+
+    while !b.is_nought() {
+        let r = divrem_gaussian(&a, &b).unwrap().1;
+        a = b;
+        b = r;
+    }
+
+    a.normalize()
+}
+
+/// Factorizes `num` into Gaussian primes.
+///
+/// Norm of `num` is factorized first. Rational prime 2 gives prime 1 +i, prime
+/// p ≡ 3 (mod 4) stays prime, prime p ≡ 1 (mod 4) splits into π ⋅π̄,
+/// π = gcd(p, x +i), x² ≡ -1 (mod p).
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns tuple with unit u and Gaussian primes in first quadrant with their
+/// exponents in order, `num` = u ⋅Π πᵉ, or `None` when `num` is nought. Primes are
+/// ordered by norm, conjugate pair by real part.
+pub fn factorize_gaussian(num: &GaussianInt) -> Option<(GaussianInt, Vec<(GaussianInt, u32)>)> {
+    if num.is_nought() {
+        return None;
+    }
+
+    let mut z = num.clone();
+    let mut factors = Vec::new();
+
+    for (p, _) in factorize(&num.norm()) {
+        let primes = match rem_num_raw(&p.row, 4) {
+            2 => vec![GInt::new_from_num(1, 1)],
+            3 => vec![GInt::new(SRow::from(p), SRow::nought())],
+            _ => {
+                let p_less = sub(&p, &Row::unity()).unwrap();
+                let x = sqrt_mod(&p_less, &p).unwrap();
+                let pi = gcd_gaussian(
+                    &GInt::new(SRow::from(p), SRow::nought()),
+                    &GInt::new(SRow::from(x), SRow::unity()),
+                );
+                let pi_conj = pi.conj().normalize();
+
+                match rel(&pi.re.mag, &pi_conj.re.mag) {
+                    Rel::Greater(_) => vec![pi_conj, pi],
+                    _ => vec![pi, pi_conj],
+                }
+            }
+        };
+
+        for pi in primes {
+            let mut e = 0;
+            loop {
+                let (ratio, rem) = divrem_gaussian(&z, &pi).unwrap();
+                if !rem.is_nought() {
+                    break;
+                }
+
+                z = ratio;
+                e += 1;
+            }
+
+            if e > 0 {
+                factors.push((pi, e));
+            }
+        }
+    }
+
+    Some((z, factors))
+}
+
+/// Computes `base` raised to `exp` modulo `modulus`.
+///
+/// Exponent is processed place by place, each place of `exp` requires
+/// tenth power of intermediate result.
+///
+/// Returns `PlacesRow` with result or `None` when `modulus` is nought.
+pub fn modpow(base: &PlacesRow, exp: &PlacesRow, modulus: &PlacesRow) -> Option<PlacesRow> {
+    if let Some(res) = modpow_shortcut(exp, modulus) {
+        return res;
+    }
+
+    let base = rem(base, modulus);
+    let res = pow_places(&base, exp, Row::unity(), |a, b| mulmod(a, b, modulus));
+
+    Some(res)
+}
+
+/// Computes power `exp` of `base` place by place of `exp` from highest one.
+///
+/// `unity` is identity of multiplication `mul`, usually modular one.
+fn pow_places(base: &Row, exp: &Row, unity: Row, mul: impl Fn(&Row, &Row) -> Row) -> Row {
+    // base⁰, base¹, …, base⁹
+    let mut powers = Vec::with_capacity(10);
+    powers.push(unity.clone());
+    for ix in 1..10 {
+        let power = mul(&powers[ix - 1], base);
+        powers.push(power);
+    }
+
+    let mut res = unity;
+    for &place in exp.row.iter().rev() {
+        // res¹⁰ = ((res²)² ⋅res)²
+        let square = mul(&res, &res);
+        let fourth = mul(&square, &square);
+        let fifth = mul(&fourth, &res);
+        res = mul(&fifth, &fifth);
+
+        if place != 0 {
+            res = mul(&res, &powers[place as usize]);
+        }
+    }
+
+    res
+}
+
+// x mod 0, illegal
+// x mod 1 = 0
+// x⁰ mod m = 1
+fn modpow_shortcut(exp: &PlacesRow, modulus: &PlacesRow) -> Option<Option<Row>> {
+    let shortcut = if modulus.is_nought() {
+        None
+    } else if modulus.is_unity() {
+        Some(Row::nought())
+    } else if exp.is_nought() {
+        Some(Row::unity())
+    } else {
+        return None;
+    };
+
+    Some(shortcut)
+}
+
+/// Modular reduction context.
+///
+/// Holds modulus m of k places and Barrett constant μ = ⌊10²ᵏ ÷m⌋, so that repeated
+/// reductions modulo m need only multiplications and place shifts.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ModContext {
+    modulus: PlacesRow,
+    mu: PlacesRow,
+    k: usize,
+}
+
+impl ModContext {
+    /// Ctor for `modulus`.
+    ///
+    /// Returns `ModContext` or `None` when `modulus` is nought.
+    pub fn new(modulus: &PlacesRow) -> Option<Self> {
+        if modulus.is_nought() {
+            return None;
+        }
+
+        let k = modulus.len();
+        let mu = divrem(&ten_power(2 * k), modulus).unwrap().0;
+
+        Some(ModContext {
+            modulus: modulus.clone(),
+            mu,
+            k,
+        })
+    }
+
+    /// Returns modulus.
+    pub fn modulus(&self) -> &PlacesRow {
+        &self.modulus
+    }
+
+    /// Computes `num` modulo modulus using Barrett reduction.
+    ///
+    /// Numbers of more than 2k places, k being places count of modulus, are reduced by
+    /// `fn divrem`.
+    ///
+    /// Returns `PlacesRow` with result.
+    pub fn reduce(&self, num: &PlacesRow) -> PlacesRow {
+        let k = self.k;
+        if num.len() > 2 * k {
+            return rem(num, &self.modulus);
+        }
+
+        if let Rel::Lesser(_) = rel(num, &self.modulus) {
+            return num.clone();
+        }
+
+        // q = ⌊⌊x ÷10ᵏ⁻¹⌋ ⋅μ ÷10ᵏ⁺¹⌋, x -q ⋅m < 3m
+        let q1 = shift_places_down(num, k - 1);
+        let q = shift_places_down(&mul(&q1, &self.mu), k + 1);
+
+        let mut r = sub(num, &mul(&q, &self.modulus)).unwrap();
+        while let Some(diff) = sub(&r, &self.modulus) {
+            r = diff;
+        }
+
+        r
+    }
+
+    /// Creates `ModInt` of value `num` modulo modulus.
+    pub fn int(&self, num: &PlacesRow) -> ModInt<'_> {
+        ModInt {
+            ctx: self,
+            value: self.reduce(num),
+        }
+    }
+
+    fn mulmod(&self, factor1: &Row, factor2: &Row) -> Row {
+        self.reduce(&mul(factor1, factor2))
+    }
+}
+
+/// Computes ⌊`num` ÷10ᵖˡᵃᶜᵉˢ⌋ by place shift.
+fn shift_places_down(num: &Row, places: usize) -> Row {
+    if places >= num.len() {
+        return Row::nought();
+    }
+
+    Row {
+        row: num.row[places..].to_vec(),
+    }
+}
+
+/// `ModInt` represents residue modulo modulus of bound `ModContext`.
+///
+/// Value always lies in [0, m). Binary operations on operands bound to different
+/// moduli yield `None`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ModInt<'a> {
+    ctx: &'a ModContext,
+    value: PlacesRow,
+}
+
+impl<'a> ModInt<'a> {
+    /// Returns value.
+    pub fn value(&self) -> &PlacesRow {
+        &self.value
+    }
+
+    /// Returns bound context.
+    pub fn ctx(&self) -> &'a ModContext {
+        self.ctx
+    }
+
+    /// Computes sum with `addend`.
+    ///
+    /// Returns `ModInt` with result or `None` when `addend` is bound to other modulus.
+    pub fn add(&self, addend: &ModInt) -> Option<ModInt<'a>> {
+        if !self.same_ctx(addend) {
+            return None;
+        }
+
+        let value = addmod(&self.value, &addend.value, &self.ctx.modulus);
+        Some(self.with(value))
+    }
+
+    /// Computes difference with `subtrahend`.
+    ///
+    /// Returns `ModInt` with result or `None` when `subtrahend` is bound to other modulus.
+    pub fn sub(&self, subtrahend: &ModInt) -> Option<ModInt<'a>> {
+        if !self.same_ctx(subtrahend) {
+            return None;
+        }
+
+        let value = submod(&self.value, &subtrahend.value, &self.ctx.modulus);
+        Some(self.with(value))
+    }
+
+    /// Computes product with `factor`.
+    ///
+    /// Returns `ModInt` with result or `None` when `factor` is bound to other modulus.
+    pub fn mul(&self, factor: &ModInt) -> Option<ModInt<'a>> {
+        if !self.same_ctx(factor) {
+            return None;
+        }
+
+        let value = self.ctx.mulmod(&self.value, &factor.value);
+        Some(self.with(value))
+    }
+
+    /// Computes power `exp`.
+    pub fn pow(&self, exp: &PlacesRow) -> ModInt<'a> {
+        let ctx = self.ctx;
+        let unity = ctx.reduce(&Row::unity());
+        let res = pow_places(&self.value, exp, unity, |a, b| ctx.mulmod(a, b));
+
+        self.with(res)
+    }
+
+    /// Computes multiplicative inverse.
+    ///
+    /// Returns `ModInt` with result or `None` when value and modulus are not coprime.
+    pub fn inv(&self) -> Option<ModInt<'a>> {
+        modinv(&self.value, &self.ctx.modulus).map(|value| self.with(value))
+    }
+
+    /// Computes additive inverse.
+    pub fn neg(&self) -> ModInt<'a> {
+        let value = submod(&Row::nought(), &self.value, &self.ctx.modulus);
+        self.with(value)
+    }
+
+    fn with(&self, value: Row) -> ModInt<'a> {
+        ModInt {
+            ctx: self.ctx,
+            value,
+        }
+    }
+
+    fn same_ctx(&self, other: &ModInt) -> bool {
+        core::ptr::eq(self.ctx, other.ctx) || self.ctx.modulus == other.ctx.modulus
+    }
+}
+
+/// `FixedDecimal` represents N-place decimal integer wrapping modulo 10ᴺ.
+///
+/// Places are stored inline, ordered from ones to highest place, and arithmetic
+/// does not allocate. Overflow wraps around like odometer does, 999 +1 = 000.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FixedDecimal<const N: usize> {
+    places: [u8; N],
+}
+
+impl<const N: usize> FixedDecimal<N> {
+    /// Handy ctor for usage with _classic_ primitive numeric data type.
+    ///
+    /// Value is taken modulo 10ᴺ.
+    pub fn new_from_num(mut num: u128) -> Self {
+        let mut places = [0; N];
+        for p in places.iter_mut() {
+            if num == 0 {
+                break;
+            }
+
+            *p = (num % 10) as u8;
+            num /= 10;
+        }
+
+        FixedDecimal { places }
+    }
+
+    /// Ctor from `PlacesRow`.
+    ///
+    /// Value is taken modulo 10ᴺ.
+    pub fn new_from_row(row: &PlacesRow) -> Self {
+        let mut places = [0; N];
+        for (p, &r) in places.iter_mut().zip(row.row.iter()) {
+            *p = r;
+        }
+
+        FixedDecimal { places }
+    }
+
+    /// Handy ctor for usage with long numbers.
+    ///
+    /// Only digits are allowed in `s`. Value is taken modulo 10ᴺ.
+    ///
+    /// Returns `FixedDecimal` or index in `s` where uncovertable `char` was
+    /// encountered. `None` for empty string.
+    pub fn new_from_str(s: &str) -> Result<Self, Option<usize>> {
+        if s.is_empty() {
+            return Err(None);
+        }
+
+        let mut places = [0; N];
+        for (ix, c) in s.bytes().rev().enumerate() {
+            if !c.is_ascii_digit() {
+                return Err(Some(s.len() - 1 - ix));
+            }
+
+            if ix < N {
+                places[ix] = c - b'0';
+            }
+        }
+
+        Ok(FixedDecimal { places })
+    }
+
+    /// Returns `String` representation without leading zeros.
+    pub fn to_number(&self) -> String {
+        self.to_row().to_number()
+    }
+
+    /// Returns `String` representation of all `N` places, leading zeros included.
+    pub fn to_places(&self) -> String {
+        self.places
+            .iter()
+            .rev()
+            .map(|&p| char::from(b'0' + p))
+            .collect()
+    }
+
+    /// Converts to `PlacesRow`.
+    pub fn to_row(&self) -> PlacesRow {
+        let len = self
+            .places
+            .iter()
+            .rposition(|&p| p > 0)
+            .map_or(0, |ix| ix + 1);
+        if len == 0 {
+            return Row::nought();
+        }
+
+        Row {
+            row: self.places[..len].to_vec(),
+        }
+    }
+
+    /// Returns places, ordered from ones to highest place.
+    pub fn places(&self) -> &[u8; N] {
+        &self.places
+    }
+
+    /// Returns nought.
+    pub fn nought() -> Self {
+        FixedDecimal { places: [0; N] }
+    }
+
+    /// Returns unity or nought when `N` is `0`.
+    pub fn unity() -> Self {
+        Self::new_from_num(1)
+    }
+
+    /// Returns greatest value, 10ᴺ -1.
+    pub fn max() -> Self {
+        FixedDecimal { places: [9; N] }
+    }
+
+    /// `true` if and only if `FixedDecimal` is _nought_ value.
+    pub fn is_nought(&self) -> bool {
+        self.places.iter().all(|&p| p == 0)
+    }
+
+    /// Computes sum with `addend`.
+    ///
+    /// Returns tuple of sum modulo 10ᴺ and `bool` overflow indication.
+    pub fn overflowing_add(&self, addend: &Self) -> (Self, bool) {
+        let mut places = [0; N];
+        let mut carry = 0;
+        for (p, (&p1, &p2)) in places
+            .iter_mut()
+            .zip(self.places.iter().zip(&addend.places))
+        {
+            let sum = p1 + p2 + carry;
+            *p = sum % 10;
+            carry = sum / 10;
+        }
+
+        (FixedDecimal { places }, carry > 0)
+    }
+
+    /// Computes difference with `subtrahend`.
+    ///
+    /// Returns tuple of difference modulo 10ᴺ and `bool` overflow indication.
+    pub fn overflowing_sub(&self, subtrahend: &Self) -> (Self, bool) {
+        let mut places = [0; N];
+        let mut borrow = 0;
+        for (p, (&p1, &p2)) in places
+            .iter_mut()
+            .zip(self.places.iter().zip(&subtrahend.places))
+        {
+            let diff = 10 + p1 - p2 - borrow;
+            *p = diff % 10;
+            borrow = if diff < 10 { 1 } else { 0 };
+        }
+
+        (FixedDecimal { places }, borrow > 0)
+    }
+
+    /// Computes product with `factor`.
+    ///
+    /// Returns tuple of product modulo 10ᴺ and `bool` overflow indication.
+    pub fn overflowing_mul(&self, factor: &Self) -> (Self, bool) {
+        let mut places = [0; N];
+        let mut overflow = false;
+        for ix in 0..N {
+            let p1 = self.places[ix];
+            if p1 == 0 {
+                continue;
+            }
+
+            // places reaching beyond 10ᴺ are lost
+            let mut carry = 0;
+            for jx in 0..N - ix {
+                let prod = p1 * factor.places[jx] + places[ix + jx] + carry;
+                places[ix + jx] = prod % 10;
+                carry = prod / 10;
+            }
+
+            overflow |= carry > 0 || factor.places[N - ix..].iter().any(|&p| p > 0);
+        }
+
+        (FixedDecimal { places }, overflow)
+    }
+
+    /// Computes power `exp`.
+    ///
+    /// Returns tuple of power modulo 10ᴺ and `bool` overflow indication.
+    pub fn overflowing_pow(&self, mut exp: u32) -> (Self, bool) {
+        let unity = Self::unity();
+        if exp == 0 {
+            return (unity, N == 0);
+        }
+
+        let mut base = *self;
+        let mut res = unity;
+        let mut overflow = false;
+
+        // every squared base is used when exp > 1 to come
+        while exp > 1 {
+            if exp & 1 == 1 {
+                let (prod, o) = res.overflowing_mul(&base);
+                res = prod;
+                overflow |= o;
+            }
+
+            exp >>= 1;
+            let (square, o) = base.overflowing_mul(&base);
+            base = square;
+            overflow |= o;
+        }
+
+        let (prod, o) = res.overflowing_mul(&base);
+        (prod, overflow || o)
+    }
+
+    /// Computes additive inverse.
+    ///
+    /// Returns tuple of inverse modulo 10ᴺ and `bool` overflow indication, that is `true`
+    /// for any value other than nought.
+    pub fn overflowing_neg(&self) -> (Self, bool) {
+        Self::nought().overflowing_sub(self)
+    }
+
+    /// Computes sum with `addend` modulo 10ᴺ.
+    pub fn wrapping_add(&self, addend: &Self) -> Self {
+        self.overflowing_add(addend).0
+    }
+
+    /// Computes difference with `subtrahend` modulo 10ᴺ.
+    pub fn wrapping_sub(&self, subtrahend: &Self) -> Self {
+        self.overflowing_sub(subtrahend).0
+    }
+
+    /// Computes product with `factor` modulo 10ᴺ.
+    pub fn wrapping_mul(&self, factor: &Self) -> Self {
+        self.overflowing_mul(factor).0
+    }
+
+    /// Computes power `exp` modulo 10ᴺ.
+    pub fn wrapping_pow(&self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Computes additive inverse modulo 10ᴺ.
+    pub fn wrapping_neg(&self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    /// Computes sum with `addend`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` on overflow.
+    pub fn checked_add(&self, addend: &Self) -> Option<Self> {
+        fixed_checked(self.overflowing_add(addend))
+    }
+
+    /// Computes difference with `subtrahend`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` on overflow.
+    pub fn checked_sub(&self, subtrahend: &Self) -> Option<Self> {
+        fixed_checked(self.overflowing_sub(subtrahend))
+    }
+
+    /// Computes product with `factor`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` on overflow.
+    pub fn checked_mul(&self, factor: &Self) -> Option<Self> {
+        fixed_checked(self.overflowing_mul(factor))
+    }
+
+    /// Computes power `exp`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` on overflow.
+    pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+        fixed_checked(self.overflowing_pow(exp))
+    }
+
+    /// Computes additive inverse.
+    ///
+    /// Returns `FixedDecimal` with result or `None` for any value other than nought.
+    pub fn checked_neg(&self) -> Option<Self> {
+        fixed_checked(self.overflowing_neg())
+    }
+
+    /// Computes ratio with `divisor`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` when `divisor` is nought.
+    pub fn checked_div(&self, divisor: &Self) -> Option<Self> {
+        self.checked_divrem(divisor).map(|(ratio, _)| ratio)
+    }
+
+    /// Computes remainder of division by `divisor`.
+    ///
+    /// Returns `FixedDecimal` with result or `None` when `divisor` is nought.
+    pub fn checked_rem(&self, divisor: &Self) -> Option<Self> {
+        self.checked_divrem(divisor).map(|(_, rem)| rem)
+    }
+
+    /// Computes ratio and remainder of division by `divisor`.
+    ///
+    /// Returns tuple with results or `None` when `divisor` is nought.
+    pub fn checked_divrem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_nought() {
+            return None;
+        }
+
+        let mut ratio = [0; N];
+        // remainder with its place above N
+        let mut rem = [0; N];
+
+        for ix in (0..N).rev() {
+            // rem = rem ⋅10 +place
+            let mut top = rem[N - 1];
+            rem.copy_within(0..N - 1, 1);
+            rem[0] = self.places[ix];
+
+            let mut count = 0;
+            while top > 0 || !fixed_lesser(&rem, &divisor.places) {
+                let mut borrow = 0;
+                for (r, &d) in rem.iter_mut().zip(divisor.places.iter()) {
+                    let diff = 10 + *r - d - borrow;
+                    *r = diff % 10;
+                    borrow = if diff < 10 { 1 } else { 0 };
+                }
+
+                top -= borrow;
+                count += 1;
+            }
+
+            ratio[ix] = count;
+        }
+
+        Some((FixedDecimal { places: ratio }, FixedDecimal { places: rem }))
+    }
+
+    /// Computes sum with `addend`.
+    ///
+    /// Returns sum or greatest value on overflow.
+    pub fn saturating_add(&self, addend: &Self) -> Self {
+        self.checked_add(addend).unwrap_or_else(Self::max)
+    }
+
+    /// Computes difference with `subtrahend`.
+    ///
+    /// Returns difference or nought on overflow.
+    pub fn saturating_sub(&self, subtrahend: &Self) -> Self {
+        self.checked_sub(subtrahend).unwrap_or_else(Self::nought)
+    }
+
+    /// Computes product with `factor`.
+    ///
+    /// Returns product or greatest value on overflow.
+    pub fn saturating_mul(&self, factor: &Self) -> Self {
+        self.checked_mul(factor).unwrap_or_else(Self::max)
+    }
+
+    /// Computes power `exp`.
+    ///
+    /// Returns power or greatest value on overflow.
+    pub fn saturating_pow(&self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap_or_else(Self::max)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedDecimal<N> {
+    /// Writes `String` representation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_number())
+    }
+}
+
+fn fixed_checked<T>((res, overflow): (T, bool)) -> Option<T> {
+    if overflow {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+fn fixed_lesser(places: &[u8], comparand: &[u8]) -> bool {
+    for ix in (0..places.len()).rev() {
+        if places[ix] != comparand[ix] {
+            return places[ix] < comparand[ix];
+        }
+    }
+
+    false
+}
+
+/// Checks relation of `num` to `comparand`.
+///
+/// Returns `Rel` relation without decimal difference information.
+pub fn rel_fixed<const N: usize>(num: &FixedDecimal<N>, comparand: &FixedDecimal<N>) -> Rel {
+    if fixed_lesser(&num.places, &comparand.places) {
+        Rel::Lesser(None)
+    } else if num == comparand {
+        Rel::Equal
+    } else {
+        Rel::Greater(None)
+    }
+}
+
+/// Primes lesser than 100.
+const SMALL_PRIMES: [u8; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Any number lesser than this bound is correctly classified by Miller–Rabin test
+/// with first 13 primes used as bases.
+const MILLER_RABIN_DETERMINISTIC_BOUND: &str = "3317044064679887385961981";
+
+/// Tests whether `num` is prime using Miller–Rabin test.
+///
+/// Trial division by primes lesser than 100 precedes. For `num` lesser than
+/// 3,317,044,064,679,887,385,961,981 deterministic base set is used and `rounds`
+/// are ignored, result is exact. Otherwise first `rounds` bases of fixed sequence are
+/// used, primes lesser than 100 followed by consecutive numbers from 101, at least 1
+/// round is performed. Test is deterministic over these bases, composite `num`
+/// constructed to pass all of them is reported as probable prime. Check with
+/// `fn is_bpsw_prime`.
+///
+/// Potentially CPU intensive.
+///
+/// Returns `false` for composite `num`, `true` for prime or probable prime `num`.
+pub fn is_probable_prime(num: &PlacesRow, rounds: usize) -> bool {
+    if let Some(res) = primality_shortcut(num) {
+        return res;
+    }
+
+    let bound = Row::new_from_str(MILLER_RABIN_DETERMINISTIC_BOUND).unwrap();
+    let rounds = if let Rel::Lesser(_) = rel(num, &bound) {
+        13
+    } else {
+        rounds.max(1)
+    };
+
+    let witness = MillerRabin::new(num);
+    for ix in 0..rounds {
+        let base = match SMALL_PRIMES.get(ix) {
+            Some(&p) => p as u128,
+            None => (ix + 76) as u128,
+        };
+
+        if !witness.passes(&Row::new_from_num(base)) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests whether `num` is prime using Baillie–PSW test.
+///
+/// Test is composed of trial division by primes lesser than 100, Miller–Rabin test
+/// to base 2 and strong Lucas test with Selfridge parameters. There is no known
+/// composite number passing this test.
+///
+/// Potentially CPU intensive.
+///
+/// Returns `false` for composite `num`, `true` for prime or probable prime `num`.
+pub fn is_bpsw_prime(num: &PlacesRow) -> bool {
+    if let Some(res) = primality_shortcut(num) {
+        return res;
+    }
+
+    if !MillerRabin::new(num).passes(&Row::new_from_num(2)) {
+        return false;
+    }
+
+    strong_lucas(num)
+}
+
+// 0, 1 are not primes
+// n ≤ 100² is decided by trial division
+// n divisible by small prime is composite unless equal to it
+fn primality_shortcut(num: &PlacesRow) -> Option<bool> {
+    let row = &num.row;
+    if let Some(n) = to_num_raw(row) {
+        if n < 2 {
+            return Some(false);
+        }
+    }
+
+    for p in SMALL_PRIMES {
+        if rem_num_raw(row, p as u64) == 0 {
+            return Some(to_num_raw(row) == Some(p as u128));
+        }
+    }
+
+    match to_num_raw(row) {
+        Some(n) if n < 10_000 => Some(true),
+        _ => None,
+    }
+}
+
+/// Miller–Rabin witness check context for odd `num`.
+struct MillerRabin<'a> {
+    num: &'a Row,
+    // num -1
+    num_less: Row,
+    // odd part of num -1
+    odd: Row,
+    // power of 2 in num -1
+    twos: usize,
+}
+
+impl<'a> MillerRabin<'a> {
+    fn new(num: &'a Row) -> Self {
+        let num_less = sub(num, &Row::unity()).unwrap();
+        let (odd, twos) = odd_part(&num_less);
+
+        Self {
+            num,
+            num_less,
+            odd,
+            twos,
+        }
+    }
+
+    /// `true` if `num` is strong probable prime to `base`.
+    fn passes(&self, base: &Row) -> bool {
+        let num = self.num;
+        let base = rem(base, num);
+        if base.is_nought() {
+            return true;
+        }
+
+        let mut x = modpow(&base, &self.odd, num).unwrap();
+        if x.is_unity() || x == self.num_less {
+            return true;
+        }
+
+        for _ in 1..self.twos {
+            x = mulmod(&x, &x, num);
+            if x == self.num_less {
+                return true;
+            }
+
+            if x.is_unity() {
+                return false;
+            }
+        }
+
+        false
+    }
+}
+
+/// Strong Lucas probable prime test with Selfridge method A parameters.
+///
+/// Expects odd `num` not divisible by primes lesser than 100.
+fn strong_lucas(num: &Row) -> bool {
+    // D = 5, -7, 9, -11, …, negative D is represented by `num` -|D|
+    let mut d_abs = 5;
+    let mut d_neg = false;
+    let d = loop {
+        let d = Row::new_from_num(d_abs);
+        let d = if d_neg { sub(num, &d).unwrap() } else { d };
+
+        match jacobi(&d, num) {
+            Some(-1) => break d,
+            // D shares factor with `num`, D is always lesser
+            Some(0) => return false,
+            _ => {}
+        }
+
+        // perfect square has no D with (D/n) = -1
+        if d_abs == 13 && isqrt(num).1.is_nought() {
+            return false;
+        }
+
+        d_abs += 2;
+        d_neg = !d_neg;
+    };
+
+    // Q = (1 -D) ÷4
+    let q = if d_neg {
+        Row::new_from_num((1 + d_abs) / 4)
+    } else {
+        sub(num, &Row::new_from_num((d_abs - 1) / 4)).unwrap()
+    };
+
+    let num_more = add(num, &Row::unity());
+    let (odd, twos) = odd_part(&num_more);
+
+    // U₁ = 1, V₁ = P = 1, Q¹ = Q
+    let mut u = Row::unity();
+    let mut v = Row::unity();
+    let mut qk = q.clone();
+
+    let bits = bits(&odd);
+    for &bit in bits.iter().rev().skip(1) {
+        // U₂ₖ = Uₖ⋅Vₖ, V₂ₖ = Vₖ² -2Qᵏ
+        u = mulmod(&u, &v, num);
+        let qk2 = add(&qk, &qk);
+        v = submod(&mulmod(&v, &v, num), &qk2, num);
+        qk = mulmod(&qk, &qk, num);
+
+        if bit {
+            // Uₖ₊₁ = (P⋅Uₖ +Vₖ) ÷2, Vₖ₊₁ = (D⋅Uₖ +P⋅Vₖ) ÷2
+            let u_next = halvemod(&addmod(&u, &v, num), num);
+            let v_next = halvemod(&addmod(&mulmod(&d, &u, num), &v, num), num);
+            u = u_next;
+            v = v_next;
+            qk = mulmod(&qk, &q, num);
+        }
+    }
+
+    if u.is_nought() || v.is_nought() {
+        return true;
+    }
+
+    for _ in 1..twos {
+        let qk2 = add(&qk, &qk);
+        v = submod(&mulmod(&v, &v, num), &qk2, num);
+        if v.is_nought() {
+            return true;
+        }
+
+        qk = mulmod(&qk, &qk, num);
+    }
+
+    false
+}
+
+/// Primes lesser than this bound are used for segmented sieve.
+const SEGMENT_SIEVE_BOUND: u64 = 100_000;
+
+/// Width of segment sieved at once.
+const SEGMENT_WIDTH: usize = 10_000;
+
+/// Finds least prime greater than `num`.
+///
+/// Candidates are sieved by primes lesser than 100,000 first, then tested with
+/// `fn is_bpsw_prime` when necessary.
+///
+/// Returns `PlacesRow` with result.
+pub fn next_prime(num: &PlacesRow) -> PlacesRow {
+    let primes = primes_below(SEGMENT_SIEVE_BOUND);
+    let mut start = add(num, &Row::unity());
+
+    loop {
+        for offset in sieve_segment(&start, SEGMENT_WIDTH, &primes) {
+            let cand = add(&start, &Row::new_from_num(offset as u128));
+            if is_sieved_prime(&cand) {
+                return cand;
+            }
+        }
+
+        start = add(&start, &Row::new_from_num(SEGMENT_WIDTH as u128));
+    }
+}
+
+/// Finds greatest prime lesser than `num`.
+///
+/// Candidates are sieved by primes lesser than 100,000 first, then tested with
+/// `fn is_bpsw_prime` when necessary.
+///
+/// Returns `PlacesRow` with result or `None` when `num` is lesser than or equal to 2.
+pub fn prev_prime(num: &PlacesRow) -> Option<PlacesRow> {
+    let primes = primes_below(SEGMENT_SIEVE_BOUND);
+    let mut end = num.clone();
+
+    loop {
+        let width = match to_num_raw(&end.row) {
+            Some(n) if n <= 2 => return None,
+            Some(n) if n < SEGMENT_WIDTH as u128 => n as usize,
+            _ => SEGMENT_WIDTH,
+        };
+
+        let start = sub(&end, &Row::new_from_num(width as u128)).unwrap();
+        for offset in sieve_segment(&start, width, &primes).into_iter().rev() {
+            let cand = add(&start, &Row::new_from_num(offset as u128));
+            if is_sieved_prime(&cand) {
+                return Some(cand);
+            }
+        }
+
+        end = start;
+    }
+}
+
+/// Provides primes in range from `lo` up to `hi`, `hi` excluded, using segmented sieve.
+///
+/// Sieve uses primes lesser than 100,000. Candidates greater than 100,000² are
+/// tested with `fn is_bpsw_prime` then. Suitable for any `lo` and moderate range widths.
+///
+/// Returns `PrimesInRange` iterator.
+pub fn primes_in_range(lo: &PlacesRow, hi: &PlacesRow) -> PrimesInRange {
+    PrimesInRange {
+        start: lo.clone(),
+        hi: hi.clone(),
+        primes: primes_below(SEGMENT_SIEVE_BOUND),
+        offsets: Vec::new(),
+        offsets_inx: 0,
+        segment: lo.clone(),
+    }
+}
+
+/// Iterator over primes in range.
+///
+/// Primes are ordered from least. Check with `fn primes_in_range`.
+#[derive(Clone, Debug)]
+pub struct PrimesInRange {
+    // next segment start
+    start: PlacesRow,
+    hi: PlacesRow,
+    // sieving primes
+    primes: Vec<u64>,
+    // current segment candidates
+    offsets: Vec<usize>,
+    offsets_inx: usize,
+    // current segment start
+    segment: PlacesRow,
+}
+
+impl Iterator for PrimesInRange {
+    type Item = PlacesRow;
+
+    /// Returns next prime or `None` when range is exhausted.
+    fn next(&mut self) -> Option<PlacesRow> {
+        loop {
+            while let Some(&offset) = self.offsets.get(self.offsets_inx) {
+                self.offsets_inx += 1;
+
+                let cand = add(&self.segment, &Row::new_from_num(offset as u128));
+                if is_sieved_prime(&cand) {
+                    return Some(cand);
+                }
+            }
+
+            let width = match sub(&self.hi, &self.start) {
+                None => return None,
+                Some(w) if w.is_nought() => return None,
+                Some(w) => match to_num_raw(&w.row) {
+                    Some(w) if w < SEGMENT_WIDTH as u128 => w as usize,
+                    _ => SEGMENT_WIDTH,
+                },
+            };
+
+            self.offsets = sieve_segment(&self.start, width, &self.primes);
+            self.offsets_inx = 0;
+            self.segment = self.start.clone();
+            self.start = add(&self.start, &Row::new_from_num(width as u128));
+        }
+    }
+}
+
+/// Sieves segment from `start` to `start` +`width`, latter excluded, by `primes`.
+///
+/// Returns offsets from `start` of numbers having no factor in `primes` other than
+/// themselves. Nought and unity are excluded.
+fn sieve_segment(start: &Row, width: usize, primes: &[u64]) -> Vec<usize> {
+    let mut composite = vec![false; width];
+    let start_num = to_num_raw(&start.row);
+
+    // 0, 1
+    if let Some(s) = start_num {
+        for n in s..2.min(s + width as u128) {
+            composite[(n - s) as usize] = true;
+        }
+    }
+
+    for &p in primes {
+        let r = rem_num_raw(&start.row, p);
+        let mut inx = ((p - r) % p) as usize;
+
+        // multiples lesser than p² are sieved by lesser primes, `p` itself is not composite
+        if let Some(s) = start_num {
+            let square = p as u128 * p as u128;
+            if s + (inx as u128) < square {
+                if square - s >= width as u128 {
+                    continue;
+                }
+
+                inx = (square - s) as usize;
+            }
+        }
+
+        while inx < width {
+            composite[inx] = true;
+            inx += p as usize;
+        }
+    }
+
+    (0..width).filter(|&inx| !composite[inx]).collect()
+}
+
+/// Checks primality of `num` which passed `sieve_segment`.
+fn is_sieved_prime(num: &Row) -> bool {
+    let bound = SEGMENT_SIEVE_BOUND as u128;
+    match to_num_raw(&num.row) {
+        Some(n) if n < bound * bound => true,
+        _ => is_bpsw_prime(num),
+    }
+}
+
+/// Computes Jacobi symbol (a/n) of `a` and `n` using binary reciprocity algorithm.
+///
+/// Returns `-1`, `0` or `1` or `None` when `n` is not odd.
+pub fn jacobi(a: &PlacesRow, n: &PlacesRow) -> Option<i8> {
+    if n.row[0].is_multiple_of(2) {
+        return None;
+    }
+
+    let mut a = rem(a, n);
+    let mut n = n.clone();
+    let mut res = 1;
+
+    while !a.is_nought() {
+        let (odd, twos) = odd_part(&a);
+
+        // (2/n) = -1 ⟺ n ≡ 3, 5 (mod 8)
+        let n_mod_8 = rem_num_raw(&n.row, 8);
+        if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            res = -res;
+        }
+
+        // (a/n) = -(n/a) ⟺ a ≡ n ≡ 3 (mod 4)
+        if n_mod_8 % 4 == 3 && rem_num_raw(&odd.row, 4) == 3 {
+            res = -res;
+        }
+
+        a = rem(&n, &odd);
+        n = odd;
+    }
+
+    Some(if n.is_unity() { res } else { 0 })
+}
+
+/// Computes Legendre symbol (a/p) of `a` and odd prime `p`.
+///
+/// Primality of `p` is not verified. For composite odd `p` Jacobi symbol is computed.
+///
+/// Returns `-1`, `0` or `1` or `None` when `p` is not odd or is unity.
+pub fn legendre(a: &PlacesRow, p: &PlacesRow) -> Option<i8> {
+    if p.is_unity() {
+        return None;
+    }
+
+    jacobi(a, p)
+}
+
+/// Computes Kronecker symbol (a/n) of `a` and `n`.
+///
+/// Extends Jacobi symbol to any `n`. (a/0) = 1 for a = 1, 0 otherwise.
+/// (a/2) = 0 for even a, 1 for a ≡ ±1 (mod 8), -1 for a ≡ ±3 (mod 8).
+///
+/// Returns `-1`, `0` or `1`.
+pub fn kronecker(a: &PlacesRow, n: &PlacesRow) -> i8 {
+    if n.is_nought() {
+        return if a.is_unity() { 1 } else { 0 };
+    }
+
+    let (odd, twos) = odd_part(n);
+
+    let mut res = 1;
+    if twos > 0 {
+        match rem_num_raw(&a.row, 8) {
+            1 | 7 => {}
+            3 | 5 => {
+                if twos % 2 == 1 {
+                    res = -res;
+                }
+            }
+            _ => return 0,
+        }
+    }
+
+    res * jacobi(a, &odd).unwrap()
+}
+
+/// Splits `num` into odd part and power of 2.
+///
+/// `num` must not be nought.
+fn odd_part(num: &Row) -> (Row, usize) {
+    let two = Row::new_from_num(2);
+    let mut odd = num.clone();
+    let mut twos = 0;
+
+    while odd.row[0].is_multiple_of(2) {
+        odd = divrem(&odd, &two).unwrap().0;
+        twos += 1;
+    }
+
+    (odd, twos)
+}
+
+/// Binary places of `num` starting at least significant one.
+fn bits(num: &Row) -> Vec<bool> {
+    let two = Row::new_from_num(2);
+    let mut bits = Vec::new();
+    let mut num = num.clone();
+
+    while !num.is_nought() {
+        bits.push(num.row[0] % 2 == 1);
+        num = divrem(&num, &two).unwrap().0;
+    }
+
+    bits
+}
+
+// `modulus` must not be nought
+fn rem(num: &Row, modulus: &Row) -> Row {
+    divrem(num, modulus).unwrap().1
+}
+
+// `modulus` must not be nought
+fn mulmod(factor1: &Row, factor2: &Row, modulus: &Row) -> Row {
+    rem(&mul(factor1, factor2), modulus)
+}
+
+// addends must be lesser than `modulus`
+fn addmod(addend1: &Row, addend2: &Row, modulus: &Row) -> Row {
+    let sum = add(addend1, addend2);
+    match sub(&sum, modulus) {
+        Some(diff) => diff,
+        None => sum,
+    }
+}
+
+// `minuend` must be lesser than `modulus`, `subtrahend` can be any
+fn submod(minuend: &Row, subtrahend: &Row, modulus: &Row) -> Row {
+    let subtrahend = rem(subtrahend, modulus);
+    match sub(minuend, &subtrahend) {
+        Some(diff) => diff,
+        None => sub(&add(minuend, modulus), &subtrahend).unwrap(),
+    }
+}
+
+// `num` must be lesser than odd `modulus`
+fn halvemod(num: &Row, modulus: &Row) -> Row {
+    let two = Row::new_from_num(2);
+    let num = if num.row[0] % 2 == 1 {
+        add(num, modulus)
+    } else {
+        num.clone()
+    };
+
+    divrem(&num, &two).unwrap().0
+}
+
+/// Computes remainder of `row` and `divisor` without conversion of `divisor`.
+fn rem_num_raw(row: &RawRow, divisor: u64) -> u64 {
+    let divisor = divisor as u128;
+    let mut rem = 0;
+    for &place in row.iter().rev() {
+        rem = (rem * 10 + place as u128) % divisor;
+    }
+
+    rem as u64
+}
+
+/// Converts `row` into number when it fits into `u128`.
+fn to_num_raw(row: &RawRow) -> Option<u128> {
+    let mut num: u128 = 0;
+    for &place in row.iter().rev() {
+        num = num.checked_mul(10)?.checked_add(place as u128)?;
+    }
+
+    Some(num)
+}
+
+/// Computes greatest common divisor of `num1` and `num2`.
+///
+/// Returns `PlacesRow` with result. Greatest common divisor of noughts is nought.
+pub fn gcd(num1: &PlacesRow, num2: &PlacesRow) -> PlacesRow {
+    let mut a = num1.clone();
+    let mut b = num2.clone();
+
+    while !b.is_nought() {
+        let r = rem(&a, &b);
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Computes modular multiplicative inverse of `num` modulo `modulus`.
+///
+/// Returns `PlacesRow` inverse lesser than `modulus` or `None` when `modulus` is nought
+/// or `num` and `modulus` are not coprime.
+pub fn modinv(num: &PlacesRow, modulus: &PlacesRow) -> Option<PlacesRow> {
+    if modulus.is_nought() {
+        return None;
+    }
+
+    modinv_or_gcd(num, modulus).ok()
+}
+
+/// Extended Euclidean algorithm with Bézout coefficient kept modulo `modulus`.
+///
+/// Returns inverse or greatest common divisor when there is none.
+fn modinv_or_gcd(num: &Row, modulus: &Row) -> Result<Row, Row> {
+    let mut r0 = modulus.clone();
+    let mut r1 = rem(num, modulus);
+    let mut s0 = Row::nought();
+    let mut s1 = Row::unity();
+
+    while !r1.is_nought() {
+        let (ratio, r) = divrem(&r0, &r1).unwrap();
+        r0 = r1;
+        r1 = r;
+
+        let s = submod(&s0, &mul(&ratio, &s1), modulus);
+        s0 = s1;
+        s1 = s;
+    }
+
+    if r0.is_unity() {
+        Ok(rem(&s0, modulus))
+    } else {
+        Err(r0)
+    }
+}
+
+/// Solves system of congruences x ≡ rᵢ (mod mᵢ) using Chinese remainder theorem.
+///
+/// `congruences` are tuples of residue rᵢ and modulus mᵢ. Moduli do not need to be
+/// coprime.
+///
+/// Returns tuple with `PlacesRow` combined residue and `PlacesRow` combined modulus in order,
+/// or `None` when congruences are inconsistent or some modulus is nought. Combined
+/// modulus is least common multiple of moduli. Empty `congruences` are solved by
+/// x ≡ 0 (mod 1).
+pub fn crt(congruences: &[(PlacesRow, PlacesRow)]) -> Option<(PlacesRow, PlacesRow)> {
+    let mut res = Row::nought();
+    let mut modulus = Row::unity();
+
+    for (r, m) in congruences {
+        if m.is_nought() {
+            return None;
+        }
+
+        let r = rem(r, m);
+        let g = gcd(&modulus, m);
+
+        // x = res +modulus ⋅t, modulus ⋅t ≡ r -res (mod m)
+        let diff = submod(&r, &res, m);
+        let (diff, diff_rem) = divrem(&diff, &g).unwrap();
+        if !diff_rem.is_nought() {
+            return None;
+        }
+
+        let m_red = divrem(m, &g).unwrap().0;
+        let modulus_red = divrem(&modulus, &g).unwrap().0;
+        let inv = modinv(&modulus_red, &m_red).unwrap();
+        let t = mulmod(&diff, &inv, &m_red);
+
+        res = add(&res, &mul(&modulus, &t));
+        modulus = mul(&modulus, &m_red);
+    }
+
+    Some((res, modulus))
+}
+
+/// Computes square root of `a` modulo prime `p` using Tonelli–Shanks algorithm.
+///
+/// Primality of `p` is not verified. For composite `p` result is not defined.
+///
+/// Returns `PlacesRow` root r ≤ p -r, other root is p -r, or `None` when `a` is
+/// quadratic nonresidue or `p` is nought or unity.
+pub fn sqrt_mod(a: &PlacesRow, p: &PlacesRow) -> Option<PlacesRow> {
+    if p.is_nought() || p.is_unity() {
+        return None;
+    }
+
+    let a = rem(a, p);
+    if a.is_nought() || is_one_raw(&p.row, 2) {
+        return Some(a);
+    }
+
+    if legendre(&a, p)? != 1 {
+        return None;
+    }
+
+    let p_less = sub(p, &Row::unity()).unwrap();
+    let (q, s) = odd_part(&p_less);
+
+    let root = if s == 1 {
+        // r = a⁽ᵖ⁺¹⁾ᐟ⁴
+        let exp = divrem(&add(p, &Row::unity()), &Row::new_from_num(4))
+            .unwrap()
+            .0;
+        modpow(&a, &exp, p).unwrap()
+    } else {
+        tonelli_shanks(&a, p, &q, s)?
+    };
+
+    let other = sub(p, &root).unwrap();
+    match rel(&root, &other) {
+        Rel::Greater(_) => Some(other),
+        _ => Some(root),
+    }
+}
+
+// p -1 = q ⋅2ˢ, s > 1
+fn tonelli_shanks(a: &Row, p: &Row, q: &Row, s: usize) -> Option<Row> {
+    // quadratic nonresidue
+    let mut z = Row::new_from_num(2);
+    while legendre(&z, p)? != -1 {
+        z = add(&z, &Row::unity());
+        if &z == p {
+            return None;
+        }
+    }
+
+    let mut m = s;
+    let mut c = modpow(&z, q, p).unwrap();
+    let mut t = modpow(a, q, p).unwrap();
+    let exp = divrem(&add(q, &Row::unity()), &Row::new_from_num(2))
+        .unwrap()
+        .0;
+    let mut r = modpow(a, &exp, p).unwrap();
+
+    while !t.is_unity() {
+        // least i, t²ⁱ = 1
+        let mut i = 0;
+        let mut t2i = t.clone();
+        while !t2i.is_unity() {
+            t2i = mulmod(&t2i, &t2i, p);
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+
+        // b = c²ᵐ⁻ⁱ⁻¹
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = mulmod(&b, &b, p);
+        }
+
+        m = i;
+        c = mulmod(&b, &b, p);
+        t = mulmod(&t, &c, p);
+        r = mulmod(&r, &b, p);
+    }
+
+    Some(r)
+}
+
+/// Computes square roots of `a` modulo pᵏ, power of prime `p`, using Hensel lifting.
+///
+/// Primality of `p` is not verified. For composite `p` result is not defined.
+///
+/// Since roots count can be as high as p⌊ᵏᐟ²⌋, roots are described by modulus n, divisor
+/// of pᵏ, so that x² ≡ a (mod pᵏ) ⟺ x ≡ rᵢ (mod n) for some root rᵢ.
+///
+/// Returns tuple with roots rᵢ < n ordered from least and `PlacesRow` modulus n in order,
+/// or `None` when there is no root or `p` is nought or unity.
+pub fn sqrt_mod_prime_power(
+    a: &PlacesRow,
+    p: &PlacesRow,
+    k: u16,
+) -> Option<(Vec<PlacesRow>, PlacesRow)> {
+    if p.is_nought() || p.is_unity() {
+        return None;
+    }
+
+    let pk = pow(p, k);
+    let a = rem(a, &pk);
+
+    // x² ≡ 0 (mod pᵏ) ⟺ x ≡ 0 (mod p⌈ᵏᐟ²⌉)
+    if a.is_nought() {
+        return Some((vec![Row::nought()], pow(p, k.div_ceil(2))));
+    }
+
+    // a = pᵛ ⋅u, x = pᵛᐟ² ⋅y, y² ≡ u (mod pᵏ⁻ᵛ)
+    let mut u = a;
+    let mut v = 0;
+    loop {
+        let (ratio, rem) = divrem(&u, p).unwrap();
+        if !rem.is_nought() {
+            break;
+        }
+
+        u = ratio;
+        v += 1;
+    }
+
+    if v % 2 == 1 {
+        return None;
+    }
+
+    let k_unit = k - v;
+    let (roots, modulus) = if is_one_raw(&p.row, 2) {
+        sqrt_mod_two_power(&u, k_unit)?
+    } else {
+        let root = sqrt_mod(&u, p)?;
+        let modulus = pow(p, k_unit);
+        let root = hensel_lift(&u, p, root, k_unit);
+        let other = sub(&modulus, &root).unwrap();
+
+        (vec![root, other], modulus)
+    };
+
+    let scale = pow(p, v / 2);
+    let mut roots = roots.iter().map(|r| mul(r, &scale)).collect::<Vec<Row>>();
+    roots.sort_by(ord);
+
+    Some((roots, mul(&modulus, &scale)))
+}
+
+/// Lifts `root` of `a` modulo odd prime `p` to root modulo pᵏ.
+///
+/// r ← r -(r² -a) ⋅(2r)⁻¹, precision doubles each step.
+fn hensel_lift(a: &Row, p: &Row, mut root: Row, k: u16) -> Row {
+    let mut e = 1;
+    while e < k {
+        e = (2 * e).min(k);
+        let modulus = pow(p, e);
+
+        let residual = submod(&mulmod(&root, &root, &modulus), a, &modulus);
+        let inv = modinv(&add(&root, &root), &modulus).unwrap();
+        let correction = mulmod(&residual, &inv, &modulus);
+
+        root = submod(&rem(&root, &modulus), &correction, &modulus);
+    }
+
+    root
+}
+
+/// Square roots of odd `a` modulo 2ᵏ.
+fn sqrt_mod_two_power(a: &Row, k: u16) -> Option<(Vec<Row>, Row)> {
+    let modulus = pow(&Row::new_from_num(2), k);
+
+    let a_mod_8 = rem_num_raw(&a.row, 8);
+    match k {
+        1 => return Some((vec![Row::unity()], modulus)),
+        2 if a_mod_8 % 4 == 1 => return Some((vec![Row::unity(), Row::new_from_num(3)], modulus)),
+        2 => return None,
+        _ if a_mod_8 != 1 => return None,
+        _ => {}
+    }
+
+    // r² ≡ a (mod 2ʲ) lifted to 2ʲ⁺¹ by adding 2ʲ⁻¹ when needed
+    let mut root = Row::unity();
+    for j in 3..k {
+        let modulus = pow(&Row::new_from_num(2), j + 1);
+        if mulmod(&root, &root, &modulus) != rem(a, &modulus) {
+            root = add(&root, &pow(&Row::new_from_num(2), j - 1));
+        }
+    }
+
+    // ±r, ±r +2ᵏ⁻¹
+    let half = pow(&Row::new_from_num(2), k - 1);
+    let roots = vec![
+        root.clone(),
+        sub(&modulus, &root).unwrap(),
+        rem(&add(&root, &half), &modulus),
+        rem(&add(&sub(&modulus, &root).unwrap(), &half), &modulus),
+    ];
+
+    Some((roots, modulus))
+}
+
+/// Orders up to this bound are solved by baby-step giant-step, greater orders by
+/// Pollard rho.
+const BSGS_BOUND: u128 = 10_000_000_000;
+
+/// Computes discrete logarithm x, gˣ ≡ h (mod p), modulo prime `p`.
+///
+/// Order of `g` is split into prime powers by Pohlig–Hellman reduction. Logarithms in
+/// prime order subgroups are found by baby-step giant-step or Pollard rho then.
+///
+/// Primality of `p` is not verified. For composite `p` result is not defined.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with least x or `None` when there is none or `p` is nought.
+pub fn discrete_log(g: &PlacesRow, h: &PlacesRow, p: &PlacesRow) -> Option<PlacesRow> {
+    if p.is_nought() {
+        return None;
+    }
+
+    let g = rem(g, p);
+    let h = rem(h, p);
+
+    // g⁰ = 1
+    if h == rem(&Row::unity(), p) {
+        return Some(Row::nought());
+    }
+
+    if g.is_nought() || h.is_nought() {
+        return if g == h { Some(Row::unity()) } else { None };
+    }
+
+    let order = sub(p, &Row::unity()).unwrap();
+    let factors = factorize(&order);
+    let (order, factors) = mul_order(&g, p, order, factors);
+    if !modpow(&h, &order, p).unwrap().is_unity() {
+        return None;
+    }
+
+    let mut congruences = Vec::new();
+    for (q, e) in factors {
+        let qe = pow_u64(&q, e as u64);
+        let cofactor = divrem(&order, &qe).unwrap().0;
+
+        let g_qe = modpow(&g, &cofactor, p).unwrap();
+        let h_qe = modpow(&h, &cofactor, p).unwrap();
+        let x = pohlig_hellman(&g_qe, &h_qe, &q, e, p)?;
+
+        congruences.push((x, qe));
+    }
+
+    crt(&congruences).map(|(x, _)| x)
+}
+
+/// Computes multiplicative order of `g` coprime to `modulus` > 1.
+///
+/// `order` must be multiple of searched order, e.g. p -1 for prime `modulus` p, and
+/// `factors` its factorization.
+///
+/// Returns searched order along with its factorization.
+fn mul_order(
+    g: &Row,
+    modulus: &Row,
+    mut order: Row,
+    factors: Vec<(Row, u32)>,
+) -> (Row, Vec<(Row, u32)>) {
+    let mut order_factors = Vec::new();
+    for (q, e) in factors {
+        let mut e_ord = e;
+        while e_ord > 0 {
+            let reduced = divrem(&order, &q).unwrap().0;
+            if !modpow(g, &reduced, modulus).unwrap().is_unity() {
+                break;
+            }
+
+            order = reduced;
+            e_ord -= 1;
+        }
+
+        if e_ord > 0 {
+            order_factors.push((q, e_ord));
+        }
+    }
+
+    (order, order_factors)
+}
+
+/// Computes logarithm of `h` to base `g` of order qᵉ, place by place in base q.
+fn pohlig_hellman(g: &Row, h: &Row, q: &Row, e: u32, p: &Row) -> Option<Row> {
+    // γ = g^qᵉ⁻¹ of order q
+    let gamma = modpow(g, &pow_u64(q, e as u64 - 1), p).unwrap();
+    let g_inv = modinv(g, p).unwrap();
+
+    let mut x = Row::nought();
+    let mut qk = Row::unity();
+    for k in 0..e {
+        // hₖ = (g⁻ˣ ⋅h)^qᵉ⁻¹⁻ᵏ
+        let base = mulmod(&modpow(&g_inv, &x, p).unwrap(), h, p);
+        let hk = modpow(&base, &pow_u64(q, (e - 1 - k) as u64), p).unwrap();
+
+        let d = match to_num_raw(&q.row) {
+            _ if hk.is_unity() => Row::nought(),
+            Some(q_num) if q_num <= BSGS_BOUND => bsgs(&gamma, &hk, q, p)?,
+            _ => pollard_rho_log(&gamma, &hk, q, p)?,
+        };
+
+        x = add(&x, &mul(&d, &qk));
+        qk = mul(&qk, q);
+    }
+
+    Some(x)
+}
+
+/// Computes logarithm of `h` to base `g` of `order` by baby-step giant-step algorithm.
+///
+/// x = i ⋅m +j, gʲ ≡ h ⋅(g⁻ᵐ)ⁱ, m = ⌈√order⌉.
+fn bsgs(g: &Row, h: &Row, order: &Row, p: &Row) -> Option<Row> {
+    let (root, root_rem) = isqrt(order);
+    let m = if root_rem.is_nought() {
+        root
+    } else {
+        add(&root, &Row::unity())
+    };
+
+    let m_num = to_num_raw(&m.row).unwrap();
+
+    let mut baby_steps = BTreeMap::new();
+    let mut gj = rem(&Row::unity(), p);
+    for j in 0..m_num {
+        baby_steps.entry(gj.row.clone()).or_insert(j);
+        gj = mulmod(&gj, g, p);
+    }
+
+    let giant = modinv(&modpow(g, &m, p).unwrap(), p).unwrap();
+    let mut gamma = h.clone();
+    for i in 0..m_num {
+        if let Some(&j) = baby_steps.get(&gamma.row) {
+            return Some(Row::new_from_num(i * m_num + j));
+        }
+
+        gamma = mulmod(&gamma, &giant, p);
+    }
+
+    None
+}
+
+/// Computes logarithm of `h` to base `g` of prime `order` by Pollard rho algorithm.
+///
+/// Walk xᵢ = gᵃⁱ ⋅hᵇⁱ is split into 3 partitions by xᵢ mod 3. Collision found with
+/// Floyd cycle detection gives x ⋅(b₁ -b₂) ≡ a₂ -a₁ (mod order).
+fn pollard_rho_log(g: &Row, h: &Row, order: &Row, p: &Row) -> Option<Row> {
+    let step = |(x, a, b): (Row, Row, Row)| match rem_num_raw(&x.row, 3) {
+        0 => (mulmod(&x, h, p), a, addmod(&b, &Row::unity(), order)),
+        1 => (
+            mulmod(&x, &x, p),
+            addmod(&a, &a, order),
+            addmod(&b, &b, order),
+        ),
+        _ => (mulmod(&x, g, p), addmod(&a, &Row::unity(), order), b),
+    };
+
+    let mut seed = 1;
+    loop {
+        let a0 = rem(&Row::new_from_num(seed), order);
+        let start = (modpow(g, &a0, p).unwrap(), a0, Row::nought());
+
+        let mut tortoise = step(start.clone());
+        let mut hare = step(step(start));
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        if b1 != b2 {
+            let b_diff = submod(&b1, &b2, order);
+            let a_diff = submod(&a2, &a1, order);
+            let x = mulmod(&a_diff, &modinv(&b_diff, order)?, order);
+
+            return if modpow(g, &x, p).unwrap() == *h {
+                Some(x)
+            } else {
+                None
+            };
+        }
+
+        seed += 1;
+    }
+}
+
+/// Factorization enumeration.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Factorization {
+    /// Prime factors with their exponents.
+    Complete(Vec<(PlacesRow, u32)>),
+    /// Effort was exhausted before factorization completion. Holds prime factors and
+    /// composite factors, both with their exponents, in order.
+    Partial(Vec<(PlacesRow, u32)>, Vec<(PlacesRow, u32)>),
+}
+
+/// Factorizes `num` into primes.
+///
+/// Combines trial division, short run of Pollard's rho algorithm in Brent variant
+/// and Lenstra elliptic curve method with growing bounds. Primality of factors is
+/// decided by `fn is_bpsw_prime`.
+///
+/// Effort is unlimited, search lasts until all factors are proven primes. For product
+/// of large primes it may take impractically long. Check with `fn factorize_bounded`
+/// for limited effort.
+///
+/// Potentially CPU intensive.
+///
+/// Returns prime factors with their exponents ordered from least. Nought and
+/// unity have no factors.
+pub fn factorize(num: &PlacesRow) -> Vec<(PlacesRow, u32)> {
+    match factorize_bounded(num, usize::MAX) {
+        Factorization::Complete(factors) => factors,
+        Factorization::Partial(..) => unreachable!("Unlimited effort leaves no composite."),
+    }
+}
+
+/// Factorizes `num` into primes within `effort`.
+///
+/// `effort` is count of modular multiplications available for Pollard's rho algorithm
+/// and elliptic curve method, `usize::MAX` means unlimited effort. Trial division and
+/// primality tests are not counted.
+///
+/// Returns `Factorization` with factors ordered from least. Nought and unity
+/// have no factors.
+pub fn factorize_bounded(num: &PlacesRow, mut effort: usize) -> Factorization {
+    let mut primes = Vec::new();
+    let mut composites = Vec::new();
+
+    if let Some(n) = to_num_raw(&num.row) {
+        if n < 2 {
+            return Factorization::Complete(primes);
+        }
+    }
+
+    let mut pending = vec![trial_division(num, &mut primes)];
+    while let Some(n) = pending.pop() {
+        if n.is_unity() {
+            continue;
+        }
+
+        if is_bpsw_prime(&n) {
+            factor_insert(&mut primes, n, 1);
+            continue;
+        }
+
+        match factor_find(&n, &mut effort) {
+            Some(factor) => {
+                let cofactor = divrem(&n, &factor).unwrap().0;
+                pending.push(factor);
+                pending.push(cofactor);
+            }
+            None => factor_insert(&mut composites, n, 1),
+        }
+    }
+
+    factors_sort(&mut primes);
+
+    if composites.is_empty() {
+        Factorization::Complete(primes)
+    } else {
+        factors_sort(&mut composites);
+        Factorization::Partial(primes, composites)
+    }
+}
+
+/// Primes lesser than this bound are extracted by trial division.
+const TRIAL_DIVISION_BOUND: u64 = 10_000;
+
+/// Pollard's rho algorithm iterations count before elliptic curve method is employed.
+const RHO_ITERATIONS: usize = 100_000;
+
+/// Elliptic curve method stage 1 bounds with curves count for each. Stages target
+/// factors of 15, 20, 25, 30 and 35 places. Stage 2 bound is `ECM_STAGE2_RATIO`
+/// multiple of stage 1 bound.
+const ECM_STAGES: [(u64, usize); 5] = [
+    (2_000, 25),
+    (11_000, 90),
+    (50_000, 300),
+    (250_000, 700),
+    (1_000_000, 1_800),
+];
+
+const ECM_STAGE2_RATIO: u64 = 100;
+
+/// Giant step of elliptic curve method stage 2, 2 ⋅3 ⋅5 ⋅7 ⋅11.
+const ECM_STAGE2_STEP: u64 = 2_310;
+
+/// Divides out primes lesser than `TRIAL_DIVISION_BOUND`.
+///
+/// Returns cofactor.
+fn trial_division(num: &Row, factors: &mut Vec<(Row, u32)>) -> Row {
+    let mut row = num.row.clone();
+
+    for p in primes_below(TRIAL_DIVISION_BOUND) {
+        let mut exp = 0;
+        loop {
+            let (ratio, rem) = divrem_num_raw(&row, p);
+            if rem != 0 {
+                break;
+            }
+
+            row = ratio;
+            exp += 1;
+        }
+
+        if exp > 0 {
+            factors.push((Row::new_from_num(p as u128), exp));
+        }
+
+        if is_unity_raw(&row) {
+            break;
+        }
+    }
+
+    Row { row }
+}
+
+/// Finds some nontrivial factor of composite `num` not divisible by 2.
+fn factor_find(num: &Row, effort: &mut usize) -> Option<Row> {
+    // prime powers resist both methods when factor is large
+    let (root, rem) = isqrt(num);
+    if rem.is_nought() {
+        return Some(root);
+    }
+
+    let ring = Montgomery::new(num);
+    if let Some(factor) = pollard_brent(&ring, 1, effort) {
+        return Some(factor);
+    }
+
+    let mut sigma = 6;
+    let (mut bound, mut curves) = ECM_STAGES[0];
+    for stage in 1.. {
+        let primes = primes_below(bound + 1);
+        for _ in 0..curves {
+            if *effort == 0 {
+                return None;
+            }
+
+            if let Some(factor) = ecm(&ring, sigma, &primes, effort) {
+                return Some(factor);
+            }
+
+            sigma += 1;
+        }
+
+        (bound, curves) = match ECM_STAGES.get(stage) {
+            Some(&s) => s,
+            None => (bound * 4, curves * 2),
+        };
+    }
+
+    None
+}
+
+/// Pollard's rho algorithm in Brent variant with f(x) = x² +c.
+///
+/// Returns nontrivial factor of modulus of `ring` or `None` when not found within
+/// `RHO_ITERATIONS` or `effort`.
+fn pollard_brent(ring: &Montgomery, c: u128, effort: &mut usize) -> Option<Row> {
+    let num = &ring.row;
+    let c = ring.to_mont(&Row::new_from_num(c));
+    let f = |x: &Limbs| ring.add(&ring.mul(x, x), &c);
+
+    // differences are accumulated into product before gcd is computed
+    let batch = 128;
+
+    let mut y = ring.to_mont(&Row::new_from_num(2));
+    let mut ys = y.clone();
+    let mut q = ring.to_mont(&Row::unity());
+    let mut g = Row::unity();
+    let mut r = 1;
+    let mut iterations = 0;
+
+    let x = loop {
+        let x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+
+        let mut k = 0;
+        while k < r && g.is_unity() {
+            ys = y.clone();
+            for _ in 0..batch.min(r - k) {
+                y = f(&y);
+                q = ring.mul(&q, &ring.abs_diff(&x, &y));
+            }
+
+            // Montgomery form q ⋅R shares no factor with R
+            g = gcd(&row_from_limbs(&q), num);
+            k += batch;
+        }
+
+        if !g.is_unity() {
+            break x;
+        }
+
+        r *= 2;
+        iterations += 2 * r;
+        if iterations > RHO_ITERATIONS || !effort_spend(effort, 2 * r) {
+            return None;
+        }
+    };
+
+    // product went nought, repeat last batch step by step
+    if &g == num {
+        loop {
+            ys = f(&ys);
+            g = gcd(&row_from_limbs(&ring.abs_diff(&x, &ys)), num);
+            if !g.is_unity() {
+                break;
+            }
+        }
+    }
+
+    if &g == num {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+/// Lenstra elliptic curve method on Montgomery curve with Suyama parametrization.
+///
+/// Stage 1 multiplies point by prime powers of `primes`, stage 2 looks for single
+/// prime up to `ECM_STAGE2_RATIO` multiple of stage 1 bound, the greatest of `primes`.
+///
+/// Returns nontrivial factor of modulus of `ring` or `None` when curve given by `sigma`
+/// fails to find one or `effort` is exhausted.
+fn ecm(ring: &Montgomery, sigma: u128, primes: &[u64], effort: &mut usize) -> Option<Row> {
+    let num = &ring.row;
+    let nontrivial = |g: Row| {
+        if g.is_unity() || &g == num {
+            None
+        } else {
+            Some(g)
+        }
+    };
+
+    // u = σ² -5, v = 4σ
+    let sigma = Row::new_from_num(sigma);
+    let u = submod(&mulmod(&sigma, &sigma, num), &Row::new_from_num(5), num);
+    let v = mulmod(&Row::new_from_num(4), &sigma, num);
+
+    // x = u³, z = v³
+    let u3 = mulmod(&mulmod(&u, &u, num), &u, num);
+    let x = ring.to_mont(&u3);
+    let z = ring.to_mont(&mulmod(&mulmod(&v, &v, num), &v, num));
+
+    // (A +2) ÷4 = (v -u)³(3u +v) ÷16u³v
+    let vu = submod(&v, &u, num);
+    let vu3 = mulmod(&mulmod(&vu, &vu, num), &vu, num);
+    let numer = mulmod(
+        &vu3,
+        &addmod(&mulmod(&Row::new_from_num(3), &u, num), &v, num),
+        num,
+    );
+    let denom = mulmod(&mulmod(&Row::new_from_num(16), &u3, num), &v, num);
+
+    let a24 = match modinv_or_gcd(&denom, num) {
+        Ok(inv) => ring.to_mont(&mulmod(&numer, &inv, num)),
+        Err(g) => return nontrivial(g),
+    };
+
+    let curve = MontgomeryCurve { ring, a24 };
+    let mut point = (x, z);
+    let bound = primes.last().copied().unwrap_or(0);
+    for &p in primes {
+        // largest power of p not exceeding bound
+        let mut pp = p;
+        while pp <= bound / p {
+            pp *= p;
+        }
+
+        // ladder takes about 11 multiplications per bit
+        let cost = 11 * (64 - pp.leading_zeros() as usize);
+        if !effort_spend(effort, cost) {
+            return None;
+        }
+
+        point = curve.ladder(&point, pp);
+    }
+
+    let g = gcd(&row_from_limbs(&point.1), num);
+    if !g.is_unity() {
+        return nontrivial(g);
+    }
+
+    let g = ecm_stage2(&curve, &point, bound, effort)?;
+    nontrivial(gcd(&row_from_limbs(&g), num))
+}
+
+/// Elliptic curve method stage 2 in baby-step giant-step variant.
+///
+/// Each prime p from `bound` to `ECM_STAGE2_RATIO` ⋅`bound` is written as
+/// p = m ⋅D ±j, D = `ECM_STAGE2_STEP`. [p]Q is point at infinity exactly when
+/// x([m ⋅D]Q) = x([j]Q), thus differences of these x-coordinates are accumulated
+/// into product.
+///
+/// Returns product or `None` when `effort` is exhausted.
+fn ecm_stage2(
+    curve: &MontgomeryCurve,
+    q: &(Limbs, Limbs),
+    bound: u64,
+    effort: &mut usize,
+) -> Option<Limbs> {
+    let ring = curve.ring;
+    let step = ECM_STAGE2_STEP;
+    let half = step / 2;
+    let bound2 = bound * ECM_STAGE2_RATIO;
+
+    if !effort_spend(effort, 6 * half as usize) {
+        return None;
+    }
+
+    // [j]Q for odd j coprime to D, [j +2]Q = [j]Q +[2]Q with difference [j -2]Q
+    let q2 = curve.double(q);
+    let mut babies = Vec::new();
+    let (mut prev, mut curr) = (q.clone(), q.clone());
+    for j in (1..half).step_by(2) {
+        if gcd_num(j, step) == 1 {
+            babies.push((j, curr.clone()));
+        }
+
+        let next = if j == 1 {
+            curve.add(&q2, q, q)
+        } else {
+            curve.add(&curr, &q2, &prev)
+        };
+
+        prev = core::mem::replace(&mut curr, next);
+    }
+
+    let sieve_primes = primes_below(bound2.isqrt() + 1);
+    let giant = curve.ladder(q, step);
+    let mut prev = giant.clone();
+    let mut curr = curve.double(&giant);
+    let mut acc = ring.to_mont(&Row::unity());
+
+    // [m ⋅D]Q, m = 2, 3, …
+    let mut m = 2;
+    while m * step - half <= bound2 {
+        let center = m * step;
+        if center + half > bound {
+            let start = Row::new_from_num((center - half) as u128);
+            let mut is_prime = vec![false; step as usize];
+            for o in sieve_segment(&start, step as usize, &sieve_primes) {
+                let p = center - half + o as u64;
+                is_prime[o] = p > bound && p <= bound2;
+            }
+
+            let mut cost = 0;
+            for (j, baby) in babies.iter() {
+                // p = m ⋅D -j, p = m ⋅D +j
+                if is_prime[(half - j) as usize] || is_prime[(half + j) as usize] {
+                    // x₁ ⋅z₂ -x₂ ⋅z₁
+                    let diff = ring.sub(&ring.mul(&curr.0, &baby.1), &ring.mul(&baby.0, &curr.1));
+                    acc = ring.mul(&acc, &diff);
+                    cost += 3;
+                }
+            }
+
+            if !effort_spend(effort, cost + 6) {
+                return None;
+            }
+        }
+
+        let next = curve.add(&curr, &giant, &prev);
+        prev = core::mem::replace(&mut curr, next);
+        m += 1;
+    }
+
+    Some(acc)
+}
+
+fn gcd_num(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Montgomery curve in projective `X:Z` coordinates.
+struct MontgomeryCurve<'a> {
+    ring: &'a Montgomery,
+    // (A +2) ÷4
+    a24: Limbs,
+}
+
+impl MontgomeryCurve<'_> {
+    fn double(&self, p: &(Limbs, Limbs)) -> (Limbs, Limbs) {
+        let ring = self.ring;
+        let sum = ring.add(&p.0, &p.1);
+        let diff = ring.sub(&p.0, &p.1);
+        let sum2 = ring.mul(&sum, &sum);
+        let diff2 = ring.mul(&diff, &diff);
+        let t = ring.sub(&sum2, &diff2);
+
+        let x = ring.mul(&sum2, &diff2);
+        let z = ring.mul(&t, &ring.add(&diff2, &ring.mul(&self.a24, &t)));
+        (x, z)
+    }
+
+    // `d` is difference of `p` and `q`
+    fn add(&self, p: &(Limbs, Limbs), q: &(Limbs, Limbs), d: &(Limbs, Limbs)) -> (Limbs, Limbs) {
+        let ring = self.ring;
+        let u = ring.mul(&ring.sub(&p.0, &p.1), &ring.add(&q.0, &q.1));
+        let v = ring.mul(&ring.add(&p.0, &p.1), &ring.sub(&q.0, &q.1));
+        let sum = ring.add(&u, &v);
+        let diff = ring.sub(&u, &v);
+
+        let x = ring.mul(&d.1, &ring.mul(&sum, &sum));
+        let z = ring.mul(&d.0, &ring.mul(&diff, &diff));
+        (x, z)
+    }
+
+    fn ladder(&self, p: &(Limbs, Limbs), k: u64) -> (Limbs, Limbs) {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+
+        let top = 63 - k.leading_zeros();
+        for bit in (0..top).rev() {
+            if k >> bit & 1 == 1 {
+                r0 = self.add(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+
+        r0
+    }
+}
+
+/// Binary limbs ordered from least significant. Used by `Montgomery`.
+type Limbs = Vec<u64>;
+
+/// Montgomery multiplication modulo odd number on binary limbs, R = 2⁶⁴ˡⁱᵐᵇˢ.
+///
+/// Factorization methods spend most of their time in modular multiplication
+/// which is considerably faster on binary limbs than on decimal places. Residues
+/// are kept in Montgomery form x ⋅R mod n.
+struct Montgomery {
+    row: Row,
+    modulus: Limbs,
+    // -n⁻¹ mod 2⁶⁴
+    inv: u64,
+    // R² mod n
+    r2: Limbs,
+}
+
+impl Montgomery {
+    // `num` must be odd and greater than unity
+    fn new(num: &Row) -> Self {
+        let modulus = limbs_from_row(num, 0);
+        let len = modulus.len();
+
+        // Newton iteration doubles correct bits, n ⋅n ≡ 1 mod 8
+        let n0 = modulus[0];
+        let mut inv = n0;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(n0.wrapping_mul(inv)));
+        }
+
+        let mut ring = Montgomery {
+            row: num.clone(),
+            modulus,
+            inv: inv.wrapping_neg(),
+            r2: Vec::new(),
+        };
+
+        // R² = 2¹²⁸ˡⁱᵐᵇˢ by doubling of unity
+        let mut r2 = limbs_from_row(&Row::unity(), len);
+        for _ in 0..128 * len {
+            r2 = ring.add(&r2, &r2);
+        }
+
+        ring.r2 = r2;
+        ring
+    }
+
+    /// Converts `num` lesser than modulus into Montgomery form.
+    fn to_mont(&self, num: &Row) -> Limbs {
+        self.mul(&limbs_from_row(num, self.modulus.len()), &self.r2)
+    }
+
+    /// Computes a ⋅b ⋅R⁻¹ mod n, coarsely integrated operand scanning.
+    fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let n = &self.modulus[..];
+        let len = n.len();
+        let a = &a[..len];
+        let mut t = vec![0u64; len + 2];
+
+        for &bi in b.iter() {
+            let mut carry = 0;
+            for (tj, &aj) in t.iter_mut().zip(a) {
+                let s = *tj as u128 + aj as u128 * bi as u128 + carry;
+                *tj = s as u64;
+                carry = s >> 64;
+            }
+
+            let s = t[len] as u128 + carry;
+            t[len] = s as u64;
+            t[len + 1] = (s >> 64) as u64;
+
+            // t +m ⋅n is divisible by 2⁶⁴, division shifts limbs
+            let m = t[0].wrapping_mul(self.inv);
+            let mut carry = (t[0] as u128 + m as u128 * n[0] as u128) >> 64;
+            for j in 1..len {
+                let s = t[j] as u128 + m as u128 * n[j] as u128 + carry;
+                t[j - 1] = s as u64;
+                carry = s >> 64;
+            }
+
+            let s = t[len] as u128 + carry;
+            t[len - 1] = s as u64;
+            t[len] = t[len + 1] + (s >> 64) as u64;
+        }
+
+        let overflow = t[len] != 0;
+        t.truncate(len);
+        if overflow || !limbs_lesser(&t, n) {
+            limbs_sub(&mut t, n);
+        }
+
+        t
+    }
+
+    fn add(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut sum = a.clone();
+        let carry = limbs_add(&mut sum, b);
+        if carry || !limbs_lesser(&sum, &self.modulus) {
+            limbs_sub(&mut sum, &self.modulus);
+        }
+
+        sum
+    }
+
+    fn sub(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut diff = a.clone();
+        if limbs_sub(&mut diff, b) {
+            limbs_add(&mut diff, &self.modulus);
+        }
+
+        diff
+    }
+
+    fn abs_diff(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut diff = a.clone();
+        if limbs_sub(&mut diff, b) {
+            diff = b.clone();
+            limbs_sub(&mut diff, a);
+        }
+
+        diff
+    }
+}
+
+/// Converts `num` into at least `len` binary limbs.
+fn limbs_from_row(num: &Row, len: usize) -> Limbs {
+    let mut limbs = vec![0; len.max(1)];
+
+    // Horner scheme by 19 places
+    for chunk in num.row.rchunks(19) {
+        let mut carry = chunk.iter().rev().fold(0, |acc, &p| acc * 10 + p as u128);
+        let scale = 10u128.pow(chunk.len() as u32);
+        for l in limbs.iter_mut() {
+            let s = *l as u128 * scale + carry;
+            *l = s as u64;
+            carry = s >> 64;
+        }
+
+        if carry > 0 {
+            limbs.push(carry as u64);
+        }
+    }
+
+    limbs
+}
+
+/// Converts binary `limbs` into `PlacesRow`.
+fn row_from_limbs(limbs: &[u64]) -> Row {
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+    let mut limbs = limbs.to_vec();
+    let mut row = Vec::new();
+    while limbs.iter().any(|&l| l > 0) {
+        let mut rem = 0;
+        for l in limbs.iter_mut().rev() {
+            let partial = (rem as u128) << 64 | *l as u128;
+            *l = (partial / CHUNK as u128) as u64;
+            rem = (partial % CHUNK as u128) as u64;
+        }
+
+        for _ in 0..19 {
+            row.push((rem % 10) as u8);
+            rem /= 10;
+        }
+    }
+
+    shrink_to_fit_raw(&mut row);
+    if row.is_empty() {
+        return Row::nought();
+    }
+
+    Row { row }
+}
+
+fn limbs_lesser(a: &[u64], b: &[u64]) -> bool {
+    for ix in (0..a.len()).rev() {
+        if a[ix] != b[ix] {
+            return a[ix] < b[ix];
+        }
+    }
+
+    false
+}
+
+// returns carry
+fn limbs_add(a: &mut [u64], b: &[u64]) -> bool {
+    let mut carry = false;
+    for (x, &y) in a.iter_mut().zip(b) {
+        let (s, c1) = x.overflowing_add(y);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        *x = s;
+        carry = c1 || c2;
+    }
+
+    carry
+}
+
+// returns borrow
+fn limbs_sub(a: &mut [u64], b: &[u64]) -> bool {
+    let mut borrow = false;
+    for (x, &y) in a.iter_mut().zip(b) {
+        let (d, b1) = x.overflowing_sub(y);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        *x = d;
+        borrow = b1 || b2;
+    }
+
+    borrow
+}
+
+/// Decreases `effort` by `cost`, `usize::MAX` is never decreased. Returns `false`
+/// when `effort` is not sufficient.
+fn effort_spend(effort: &mut usize, cost: usize) -> bool {
+    if *effort == usize::MAX {
+        return true;
+    }
+
+    match effort.checked_sub(cost) {
+        Some(left) => {
+            *effort = left;
+            true
+        }
+        None => {
+            *effort = 0;
+            false
+        }
+    }
+}
+
+fn factor_insert(factors: &mut Vec<(Row, u32)>, factor: Row, exp: u32) {
+    match factors.iter_mut().find(|f| f.0 == factor) {
+        Some(f) => f.1 += exp,
+        None => factors.push((factor, exp)),
+    }
+}
+
+fn factors_sort(factors: &mut [(Row, u32)]) {
+    factors.sort_by(|f1, f2| ord(&f1.0, &f2.0));
+}
+
+/// Maps `Rel` to `Ordering`.
+fn ord(num: &Row, comparand: &Row) -> Ordering {
+    match rel(num, comparand) {
+        Rel::Greater(_) => Ordering::Greater,
+        Rel::Equal => Ordering::Equal,
+        Rel::Lesser(_) => Ordering::Less,
+    }
+}
+
+/// Computes ratio and remainder of `row` and `divisor` without conversion of `divisor`.
+fn divrem_num_raw(row: &RawRow, divisor: u64) -> (RawRow, u64) {
+    let divisor = divisor as u128;
+    let mut ratio = vec![0; row.len()];
+    let mut rem = 0;
+    for inx in (0..row.len()).rev() {
+        let partial = rem * 10 + row[inx] as u128;
+        ratio[inx] = (partial / divisor) as u8;
+        rem = partial % divisor;
+    }
+
+    shrink_to_fit_raw(&mut ratio);
+    (ratio, rem as u64)
+}
+
+/// Sieve of Eratosthenes.
+fn primes_below(bound: u64) -> Vec<u64> {
+    let bound = bound as usize;
+    let mut composite = vec![false; bound];
+    let mut primes = Vec::new();
+
+    for n in 2..bound {
+        if composite[n] {
+            continue;
+        }
+
+        primes.push(n as u64);
+
+        let mut multiple = n * n;
+        while multiple < bound {
+            composite[multiple] = true;
+            multiple += n;
+        }
+    }
+
+    primes
+}
+
+/// Computes least common multiple of `num1` and `num2`.
+///
+/// Returns `PlacesRow` with result. Least common multiple with nought is nought.
+pub fn lcm(num1: &PlacesRow, num2: &PlacesRow) -> PlacesRow {
+    if num1.is_nought() || num2.is_nought() {
+        return Row::nought();
+    }
+
+    let ratio = divrem(num1, &gcd(num1, num2)).unwrap().0;
+    mul(&ratio, num2)
+}
+
+/// Computes Euler totient φ(n) of number n given by its prime `factors`.
+///
+/// φ(n) = Π pᵉ⁻¹ ⋅(p -1)
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `PlacesRow` with result.
+pub fn totient(factors: &[(PlacesRow, u32)]) -> PlacesRow {
+    factors
+        .iter()
+        .filter(|f| f.1 > 0)
+        .fold(Row::unity(), |acc, (p, e)| {
+            let p_less = sub(p, &Row::unity()).unwrap();
+            mul(&acc, &mul(&pow_u64(p, *e as u64 - 1), &p_less))
+        })
+}
+
+/// Computes Carmichael function λ(n) of number n given by its prime `factors`.
+///
+/// λ(n) is least common multiple of λ(pᵉ) where λ(pᵉ) = φ(pᵉ) except for
+/// λ(2ᵉ) = 2ᵉ⁻² for e ≥ 3.
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `PlacesRow` with result.
+pub fn carmichael(factors: &[(PlacesRow, u32)]) -> PlacesRow {
+    factors
+        .iter()
+        .filter(|f| f.1 > 0)
+        .fold(Row::unity(), |acc, (p, e)| {
+            let lambda = if is_one_raw(&p.row, 2) && *e >= 3 {
+                pow_u64(p, *e as u64 - 2)
+            } else {
+                totient(&[(p.clone(), *e)])
+            };
+
+            lcm(&acc, &lambda)
+        })
+}
+
+/// Computes divisors count σ₀(n) of number n given by its prime `factors`.
+///
+/// σ₀(n) = Π (e +1)
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `PlacesRow` with result.
+pub fn divisor_count(factors: &[(PlacesRow, u32)]) -> PlacesRow {
+    factors.iter().fold(Row::unity(), |acc, (_, e)| {
+        mul(&acc, &Row::new_from_num(*e as u128 + 1))
+    })
+}
+
+/// Computes divisors `k`-th powers sum σₖ(n) of number n given by its prime `factors`.
+///
+/// σₖ(n) = Π (pᵏ⁽ᵉ⁺¹⁾ -1) ÷(pᵏ -1), k > 0
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `PlacesRow` with result.
+pub fn divisor_sum(factors: &[(PlacesRow, u32)], k: u32) -> PlacesRow {
+    if k == 0 {
+        return divisor_count(factors);
+    }
+
+    factors.iter().fold(Row::unity(), |acc, (p, e)| {
+        let pk = pow_u64(p, k as u64);
+        let numerator = sub(&pow_u64(&pk, *e as u64 + 1), &Row::unity()).unwrap();
+        let denominator = sub(&pk, &Row::unity()).unwrap();
+
+        mul(&acc, &divrem(&numerator, &denominator).unwrap().0)
+    })
+}
+
+/// Computes Möbius function μ(n) of number n given by its prime `factors`.
+///
+/// μ(n) = 0 for n with square factor, (-1)ᵏ otherwise, k being prime factors count.
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `-1`, `0` or `1`.
+pub fn mobius(factors: &[(PlacesRow, u32)]) -> i8 {
+    let mut res = 1;
+    for (_, e) in factors {
+        match e {
+            0 => {}
+            1 => res = -res,
+            _ => return 0,
+        }
+    }
+
+    res
+}
+
+/// Provides all divisors of number n given by its prime `factors`.
+///
+/// `factors` are expected in form produced by `fn factorize`, primes with their exponents.
+///
+/// Returns `Divisors` iterator.
+pub fn divisors(factors: &[(PlacesRow, u32)]) -> Divisors {
+    let factors = factors.to_vec();
+    let exps = vec![0; factors.len()];
+    let powers = vec![Row::unity(); factors.len()];
+
+    Divisors {
+        factors,
+        exps,
+        powers,
+        done: false,
+    }
+}
+
+/// Iterator over divisors of number given by prime factors.
+///
+/// Divisors are not ordered. Check with `fn divisors`.
+#[derive(Clone, Debug)]
+pub struct Divisors {
+    factors: Vec<(PlacesRow, u32)>,
+    // current exponents
+    exps: Vec<u32>,
+    // current prime powers
+    powers: Vec<PlacesRow>,
+    done: bool,
+}
+
+impl Iterator for Divisors {
+    type Item = PlacesRow;
+
+    /// Returns next divisor or `None` when all were provided.
+    fn next(&mut self) -> Option<PlacesRow> {
+        if self.done {
+            return None;
+        }
+
+        let divisor = self.powers.iter().fold(Row::unity(), |acc, p| mul(&acc, p));
+
+        // odometer over exponents
+        self.done = true;
+        for (inx, (p, e)) in self.factors.iter().enumerate() {
+            if self.exps[inx] < *e {
+                self.exps[inx] += 1;
+                self.powers[inx] = mul(&self.powers[inx], p);
+                self.done = false;
+                break;
+            }
+
+            self.exps[inx] = 0;
+            self.powers[inx] = Row::unity();
+        }
+
+        Some(divisor)
+    }
+}
+
+/// Computes integer square root of `num` using Newton iteration.
+///
+/// Returns tuple with `PlacesRow` root and `PlacesRow` remainder in order. Root is
+/// greatest number whose square does not exceed `num`, remainder is their difference.
+pub fn isqrt(num: &PlacesRow) -> (PlacesRow, PlacesRow) {
+    if num.is_nought() {
+        return (Row::nought(), Row::nought());
+    }
+
+    let two = Row::new_from_num(2);
+
+    // 10ᶜ, c = ⌈places ÷2⌉ is never lesser than root
+    let mut x = ten_power(num.len().div_ceil(2));
+
+    loop {
+        let ratio = divrem(num, &x).unwrap().0;
+        let next = divrem(&add(&x, &ratio), &two).unwrap().0;
+
+        if let Rel::Lesser(_) = rel(&next, &x) {
+            x = next;
+        } else {
+            break;
+        }
+    }
+
+    let rem = sub(num, &mul(&x, &x)).unwrap();
+    (x, rem)
+}
+
+/// Computes integer `n`-th root of `num` using Newton iteration.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns tuple with `PlacesRow` root and exactness flag in order or `None` when `n`
+/// is `0`. Root is greatest number whose `n`-th power does not exceed `num`. Flag is
+/// `true` if and only if `n`-th power of root equals `num`.
+pub fn iroot(num: &PlacesRow, n: u16) -> Option<(PlacesRow, bool)> {
+    if let Some(res) = iroot_shortcut(num, n) {
+        return res;
+    }
+
+    if n == 2 {
+        let (root, rem) = isqrt(num);
+        return Some((root, rem.is_nought()));
+    }
+
+    let n_row = Row::new_from_num(n as u128);
+    let n_less = Row::new_from_num((n - 1) as u128);
+
+    // 10ᶜ, c = ⌈places ÷n⌉ is never lesser than root
+    let mut x = ten_power(num.len().div_ceil(n as usize));
+
+    loop {
+        let ratio = divrem(num, &pow(&x, n - 1)).unwrap().0;
+        let next = divrem(&add(&mul(&n_less, &x), &ratio), &n_row).unwrap().0;
+
+        if let Rel::Lesser(_) = rel(&next, &x) {
+            x = next;
+        } else {
+            break;
+        }
+    }
+
+    let exact = &pow(&x, n) == num;
+    Some((x, exact))
+}
+
+// ⁰√x, illegal
+// ¹√x = x
+// ⁿ√0 = 0
+// ⁿ√1 = 1
+// ⁿ√x = 1, 2ⁿ > x
+fn iroot_shortcut(num: &PlacesRow, n: u16) -> Option<Option<(Row, bool)>> {
+    let shortcut = if n == 0 {
+        None
+    } else if n == 1 || num.is_nought() || num.is_unity() {
+        Some((num.clone(), true))
+    } else if n as usize >= 4 * num.len() {
+        // 2ⁿ ≥ 16ᵖ > 10ᵖ > x, p = places count
+        Some((Row::unity(), false))
+    } else {
+        return None;
+    };
+
+    Some(shortcut)
+}
+
+/// Checks whether `num` is perfect square.
+///
+/// Last two places are checked for being possible square ending first.
+pub fn is_perfect_square(num: &PlacesRow) -> bool {
+    // squares mod 100
+    const ENDINGS: [u8; 22] = [
+        0, 1, 4, 9, 16, 21, 24, 25, 29, 36, 41, 44, 49, 56, 61, 64, 69, 76, 81, 84, 89, 96,
+    ];
+
+    let ending = rem_num_raw(&num.row, 100) as u8;
+    if !ENDINGS.contains(&ending) {
+        return false;
+    }
+
+    isqrt(num).1.is_nought()
+}
+
+/// Checks whether `num` is perfect power bᵉ, e ≥ 2.
+///
+/// Residues of low places and residues modulo some small primes are checked before
+/// root is computed for each prime exponent.
+///
+/// Potentially CPU intensive.
+///
+/// Returns tuple with least `PlacesRow` base b and greatest exponent e in order, or `None`
+/// when `num` is not perfect power. Nought and unity are not considered perfect powers
+/// since they have no greatest exponent. Exponents greater than `u16::MAX` are not considered.
+pub fn is_perfect_power(num: &PlacesRow) -> Option<(PlacesRow, u32)> {
+    if num.is_nought() || num.is_unity() {
+        return None;
+    }
+
+    // 2ᵉ ≤ num < 10ᵖ ⇒ e < p ⋅log₂10 < p ⋅3.33, p = places count
+    // `fn iroot` supports exponents up to `u16::MAX`
+    let max_exp = (num.len() as u64 * 333 / 100 + 1).min(u16::MAX as u64);
+
+    for e in primes_below(max_exp + 1) {
+        let e = e as u16;
+        if !power_residue_filter(num, e) {
+            continue;
+        }
+
+        if let Some((base, true)) = iroot(num, e) {
+            if base.is_unity() {
+                continue;
+            }
+
+            return Some(match is_perfect_power(&base) {
+                Some((base, exp)) => (base, exp * e as u32),
+                None => (base, e as u32),
+            });
+        }
+    }
+
+    None
+}
+
+/// Checks whether `num` can be `exp`-th power by its residues modulo 1,000 and modulo
+/// primes q < 200, q ≡ 1 (mod `exp`).
+///
+/// Returns `false` only when `num` is surely not `exp`-th power.
+fn power_residue_filter(num: &Row, exp: u16) -> bool {
+    let exp = exp as u64;
+    let pow_mod = |mut base: u64, modulus: u64| {
+        let mut res = 1 % modulus;
+        let mut exp = exp;
+        base %= modulus;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                res = res * base % modulus;
+            }
+
+            base = base * base % modulus;
+            exp /= 2;
+        }
+
+        res
+    };
+
+    let has_root = |modulus: u64| {
+        let residue = rem_num_raw(&num.row, modulus);
+        (0..modulus).any(|x| pow_mod(x, modulus) == residue)
+    };
+
+    if !has_root(1000) {
+        return false;
+    }
+
+    primes_below(200)
+        .into_iter()
+        .filter(|q| q % exp == 1)
+        .all(has_root)
+}
+
+/// Computes terms of simple continued fraction of `num` ÷ `den`.
+///
+/// num ÷ den = a₀ + 1 ÷(a₁ + 1 ÷(a₂ + …)). Integer is expanded into sole term a₀,
+/// e.g. 0 ÷ 7 into [0]. Otherwise last term is greater than unity.
+///
+/// Returns terms ordered from a₀ or `None` when `den` is nought.
+pub fn continued_fraction(num: &PlacesRow, den: &PlacesRow) -> Option<Vec<PlacesRow>> {
+    if den.is_nought() {
+        return None;
+    }
+
+    let mut terms = Vec::new();
+    let mut a = num.clone();
+    let mut b = den.clone();
+
+    while !b.is_nought() {
+        let (ratio, rem) = divrem(&a, &b).unwrap();
+        terms.push(ratio);
+        a = b;
+        b = rem;
+    }
+
+    Some(terms)
+}
+
+/// Provides convergents pₖ ÷ qₖ of continued fraction with `terms`.
+///
+/// Convergents are best rational approximations of continued fraction value.
+///
+/// Returns `Convergents` iterator.
+pub fn convergents(terms: &[PlacesRow]) -> Convergents {
+    Convergents {
+        terms: terms.to_vec(),
+        inx: 0,
+        num: Row::unity(),
+        num_prev: Row::nought(),
+        den: Row::nought(),
+        den_prev: Row::unity(),
+    }
+}
+
+/// Iterator over convergents of continued fraction.
+///
+/// Check with `fn convergents`.
+#[derive(Clone, Debug)]
+pub struct Convergents {
+    terms: Vec<PlacesRow>,
+    inx: usize,
+    // pₖ₋₁, pₖ₋₂
+    num: PlacesRow,
+    num_prev: PlacesRow,
+    // qₖ₋₁, qₖ₋₂
+    den: PlacesRow,
+    den_prev: PlacesRow,
+}
+
+impl Iterator for Convergents {
+    type Item = (PlacesRow, PlacesRow);
+
+    /// Returns tuple with numerator pₖ and denominator qₖ in order or `None` when
+    /// terms are exhausted.
+    fn next(&mut self) -> Option<(PlacesRow, PlacesRow)> {
+        let a = self.terms.get(self.inx)?;
+        self.inx += 1;
+
+        // pₖ = aₖ ⋅pₖ₋₁ +pₖ₋₂, qₖ = aₖ ⋅qₖ₋₁ +qₖ₋₂
+        let num = add(&mul(a, &self.num), &self.num_prev);
+        let den = add(&mul(a, &self.den), &self.den_prev);
+
+        self.num_prev = core::mem::replace(&mut self.num, num.clone());
+        self.den_prev = core::mem::replace(&mut self.den, den.clone());
+
+        Some((num, den))
+    }
+}
+
+/// Computes periodic continued fraction of square root of `n`.
+///
+/// √n = [a₀; a₁, …, aᵣ, a₁, …, aᵣ, …], period ends with aᵣ = 2a₀.
+///
+/// Returns tuple with `PlacesRow` a₀ and period terms in order. Period is empty
+/// when `n` is perfect square.
+pub fn sqrt_continued_fraction(n: &PlacesRow) -> (PlacesRow, Vec<PlacesRow>) {
+    let (a0, rem) = isqrt(n);
+    let mut period = Vec::new();
+
+    if rem.is_nought() {
+        return (a0, period);
+    }
+
+    let a0_double = add(&a0, &a0);
+    let mut m = Row::nought();
+    let mut d = Row::unity();
+    let mut a = a0.clone();
+
+    while a != a0_double {
+        // mₖ₊₁ = dₖ ⋅aₖ -mₖ, dₖ₊₁ = (n -mₖ₊₁²) ÷dₖ, aₖ₊₁ = ⌊(a₀ +mₖ₊₁) ÷dₖ₊₁⌋
+        m = sub(&mul(&d, &a), &m).unwrap();
+        d = divrem(&sub(n, &mul(&m, &m)).unwrap(), &d).unwrap().0;
+        a = divrem(&add(&a0, &m), &d).unwrap().0;
+
+        period.push(a.clone());
+    }
+
+    (a0, period)
+}
+
+/// Computes fundamental solution of Pell's equation x² -n ⋅y² = 1.
+///
+/// Solution is convergent of √n at end of period, or of doubled period when its length
+/// is odd.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns tuple with least positive `PlacesRow` x and y in order or `None` when `n`
+/// is perfect square.
+pub fn pell(n: &PlacesRow) -> Option<(PlacesRow, PlacesRow)> {
+    let (a0, period) = sqrt_continued_fraction(n);
+    if period.is_empty() {
+        return None;
+    }
+
+    let mut terms = vec![a0];
+    let len = if period.len().is_multiple_of(2) {
+        period.len()
+    } else {
+        2 * period.len()
+    };
+
+    terms.extend(period.iter().cycle().take(len - 1).cloned());
+    convergents(&terms).last()
+}
+
+/// Computes integer logarithm of `num` to `base`, greatest e with baseᵉ ≤ `num`.
+///
+/// Returns `u64` logarithm or `None` when `num` is nought or `base` is lesser than 2.
+pub fn ilog(num: &PlacesRow, base: &PlacesRow) -> Option<u64> {
+    ilog_power(num, base).map(|(e, _)| e as u64)
+}
+
+/// Computes integer logarithm of `num` to base 2.
+///
+/// Returns `u64` logarithm or `None` when `num` is nought.
+pub fn ilog2(num: &PlacesRow) -> Option<u64> {
+    ilog(num, &Row::new_from_num(2))
+}
+
+/// Computes integer logarithm of `num` to base 10, equal to places count less 1.
+///
+/// Returns `u64` logarithm or `None` when `num` is nought.
+pub fn ilog10(num: &PlacesRow) -> Option<u64> {
+    if num.is_nought() {
+        None
+    } else {
+        Some(num.len() as u64 - 1)
+    }
+}
+
+/// Checks whether `num` is power baseᵉ, e ≥ 0, of `base`.
+///
+/// Returns `false` when `num` is nought or `base` is lesser than 2.
+pub fn is_power_of(num: &PlacesRow, base: &PlacesRow) -> bool {
+    match ilog_power(num, base) {
+        Some((_, power)) => &power == num,
+        None => false,
+    }
+}
+
+/// Computes greatest e with baseᵉ ≤ `num` by binary descent over base²ʲ.
+///
+/// Returns e and baseᵉ in order.
+fn ilog_power(num: &Row, base: &Row) -> Option<(usize, Row)> {
+    if num.is_nought() || base.is_nought() || base.is_unity() {
+        return None;
+    }
+
+    let num_len = num.len();
+    // product of a and b has at least places count of a +b -1
+    let exceeds = |a: &Row, b: &Row| a.len() + b.len() - 1 > num_len;
+
+    // base¹, base², base⁴, …
+    let mut powers = vec![base.clone()];
+    loop {
+        let last = powers.last().unwrap();
+        if exceeds(last, last) {
+            break;
+        }
+
+        let square = mul(last, last);
+        if let Rel::Greater(_) = rel(&square, num) {
+            break;
+        }
+
+        powers.push(square);
+    }
+
+    let mut exp = 0;
+    let mut acc = Row::unity();
+    for (j, power) in powers.iter().enumerate().rev() {
+        if exceeds(&acc, power) {
+            continue;
+        }
+
+        let cand = mul(&acc, power);
+        if let Rel::Greater(_) = rel(&cand, num) {
+            continue;
+        }
+
+        acc = cand;
+        exp += 1 << j;
+    }
+
+    Some((exp, acc))
+}
+
+/// Returns `PlacesRow` with 10ᵉˣᵖ.
+fn ten_power(exp: usize) -> Row {
+    let mut row = vec![0; exp];
+    row.push(1);
+    Row { row }
+}
+
+/// Computes factorial of `n` using prime swing algorithm.
+///
+/// n! = (⌊n ÷2⌋!)² ⋅n≀, where swing n≀ = n! ÷(⌊n ÷2⌋!)² is product of prime powers.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn factorial(n: u32) -> PlacesRow {
+    if n < 34 {
+        return product_tree(&(2..=n as u64).collect::<Vec<u64>>());
+    }
+
+    let half = factorial(n / 2);
+    let swing = prime_swing(n);
+
+    mul(&mul(&half, &half), &swing)
+}
+
+/// Computes swing n≀ = n! ÷(⌊n ÷2⌋!)².
+///
+/// Exponent of prime p in n≀ is Σ ⌊n ÷pⁱ⌋ mod 2, i ≥ 1.
+fn prime_swing(n: u32) -> Row {
+    let n = n as u64;
+    let mut factors = Vec::new();
+
+    for p in primes_below(n + 1) {
+        let mut q = n / p;
+        while q > 0 {
+            if q % 2 == 1 {
+                factors.push(p);
+            }
+
+            q /= p;
+        }
+    }
+
+    product_tree(&factors)
+}
+
+/// Computes double factorial n!! = n ⋅(n -2) ⋅(n -4) ⋅… of `n`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn double_factorial(n: u32) -> PlacesRow {
+    // (2m)!! = 2ᵐ ⋅m!
+    if n.is_multiple_of(2) {
+        let m = n / 2;
+        let two_power = pow_u64(&Row::new_from_num(2), m as u64);
+        return mul(&two_power, &factorial(m));
+    }
+
+    let factors = (1..=n as u64).rev().step_by(2).collect::<Vec<u64>>();
+    product_tree(&factors)
+}
+
+/// Binomial coefficients over lesser `k` than this bound are computed by multiplicative
+/// formula.
+const BINOMIAL_MULTIPLICATIVE_BOUND: u64 = 32;
+
+/// Computes binomial coefficient of `n` over `k`.
+///
+/// For small min(`k`, `n` -`k`) coefficient is computed by multiplicative formula,
+/// otherwise it is composed from prime powers. See `fn binomial_factors`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result. Nought when `k` > `n`.
+pub fn binomial(n: u32, k: u32) -> PlacesRow {
+    if k > n {
+        return Row::nought();
+    }
+
+    let n = n as u64;
+    let k = (k as u64).min(n - k as u64);
+
+    if k < BINOMIAL_MULTIPLICATIVE_BOUND {
+        // C(n, i) = C(n, i -1) ⋅(n -i +1) ÷i
+        let mut res = Row::unity();
+        for i in 1..=k {
+            let prod = mul(&res, &Row::new_from_num((n - i + 1) as u128));
+            res = Row {
+                row: divrem_num_raw(&prod.row, i).0,
+            };
+        }
+
+        return res;
+    }
+
+    product_tree(&binomial_factors(n, k))
+}
+
+/// Computes multinomial coefficient (k₁ +k₂ +… +kₘ)! ÷(k₁! ⋅k₂! ⋅… ⋅kₘ!) of `k`.
+///
+/// Coefficient is product of binomial coefficients C(k₁ +… +kᵢ, kᵢ) with greatest kᵢ
+/// first, each composed from prime powers. See `fn binomial_factors`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn multinomial(k: &[u32]) -> PlacesRow {
+    let mut k = k.iter().map(|&k| k as u64).collect::<Vec<u64>>();
+    k.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut n = k.first().copied().unwrap_or(0);
+    let mut factors = Vec::new();
+    for &k in k.iter().skip(1).take_while(|&&k| k > 0) {
+        n += k;
+        factors.append(&mut binomial_factors(n, k));
+    }
+
+    product_tree(&factors)
+}
+
+/// Computes prime factors of C(`n`, `k`), `k` ≤ `n` -`k`, with repetition.
+///
+/// Primes up to max(√n, k) get exponents by Legendre formula. Any greater prime
+/// divides C(n, k) at most once, exactly when it divides some of n -k +1, …, n,
+/// and it is what is left of such number divided by all primes up to √n. Thus
+/// sieve does not exceed max(√n, k).
+fn binomial_factors(n: u64, k: u64) -> Vec<u64> {
+    let root = n.isqrt();
+    let bound = root.max(k);
+    let primes = primes_below(bound + 1);
+
+    let mut factors = Vec::new();
+    for &p in primes.iter() {
+        let exp = legendre_exp(n, p) - legendre_exp(k, p) - legendre_exp(n - k, p);
+        for _ in 0..exp {
+            factors.push(p);
+        }
+    }
+
+    let mut start = n - k + 1;
+    while start <= n {
+        let end = n.min(start + SEGMENT_WIDTH as u64 - 1);
+        let mut rest = (start..=end).collect::<Vec<u64>>();
+
+        for &p in primes.iter().take_while(|&&p| p <= root) {
+            let mut inx = ((p - start % p) % p) as usize;
+            while inx < rest.len() {
+                while rest[inx] % p == 0 {
+                    rest[inx] /= p;
+                }
+
+                inx += p as usize;
+            }
+        }
+
+        factors.extend(rest.into_iter().filter(|&r| r > bound));
+        start = end + 1;
+    }
+
+    factors
+}
+
+/// Computes falling factorial x ⋅(x -1) ⋅… ⋅(x -k +1) of `x` and `k`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result. Nought when `k` > `x`.
+pub fn falling_factorial(x: u32, k: u32) -> PlacesRow {
+    if k > x {
+        return Row::nought();
+    }
+
+    let factors = ((x - k + 1) as u64..=x as u64).collect::<Vec<u64>>();
+    product_tree(&factors)
+}
+
+/// Computes rising factorial x ⋅(x +1) ⋅… ⋅(x +k -1) of `x` and `k`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn rising_factorial(x: u32, k: u32) -> PlacesRow {
+    if k > 0 && x == 0 {
+        return Row::nought();
+    }
+
+    let x = x as u64;
+    let factors = (x..x + k as u64).collect::<Vec<u64>>();
+    product_tree(&factors)
+}
+
+/// Computes primorial of `n`, product of all primes lesser than or equal to `n`.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn primorial(n: u32) -> PlacesRow {
+    product_tree(&primes_below(n as u64 + 1))
+}
+
+/// Exponent of prime `p` in n! by Legendre formula Σ ⌊n ÷pⁱ⌋, i ≥ 1.
+fn legendre_exp(mut n: u64, p: u64) -> u64 {
+    let mut exp = 0;
+    while n > 0 {
+        n /= p;
+        exp += n;
+    }
+
+    exp
+}
+
+/// Computes `base` raised to `exp` by squaring.
+fn pow_u64(base: &Row, mut exp: u64) -> Row {
+    let mut res = Row::unity();
+    let mut base = base.clone();
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            res = mul(&res, &base);
+        }
+
+        exp /= 2;
+        if exp > 0 {
+            base = mul(&base, &base);
+        }
+    }
+
+    res
+}
+
+/// Multiplies `factors` using binary splitting. Factors are packed into `u128`
+/// first, then multiplied in pairs so that operands of similar length meet.
+fn product_tree(factors: &[u64]) -> Row {
+    let mut packed = Vec::new();
+    let mut acc = 1u128;
+
+    for &f in factors {
+        match acc.checked_mul(f as u128) {
+            Some(p) => acc = p,
+            None => {
+                packed.push(Row::new_from_num(acc));
+                acc = f as u128;
+            }
+        }
+    }
+
+    packed.push(Row::new_from_num(acc));
+    product_split(&packed)
+}
+
+fn product_split(factors: &[Row]) -> Row {
+    match factors.len() {
+        0 => Row::unity(),
+        1 => factors[0].clone(),
+        len => {
+            let (lo, hi) = factors.split_at(len / 2);
+            mul(&product_split(lo), &product_split(hi))
+        }
+    }
+}
+
+/// Computes `n`-th Fibonacci number using fast doubling.
+///
+/// F₂ₖ = Fₖ ⋅(2Fₖ₊₁ -Fₖ), F₂ₖ₊₁ = Fₖ² +Fₖ₊₁²
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn fibonacci(n: u64) -> PlacesRow {
+    fibonacci_pair(n).0
+}
+
+/// Computes `n`-th Lucas number.
+///
+/// Lₙ = 2Fₙ₊₁ -Fₙ
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result.
+pub fn lucas(n: u64) -> PlacesRow {
+    let (f, f_next) = fibonacci_pair(n);
+    sub(&add(&f_next, &f_next), &f).unwrap()
+}
+
+/// Returns Fₙ and Fₙ₊₁ in order.
+fn fibonacci_pair(n: u64) -> (Row, Row) {
+    let mut f = Row::nought();
+    let mut f_next = Row::unity();
+
+    if n == 0 {
+        return (f, f_next);
+    }
+
+    let top = 63 - n.leading_zeros();
+    for bit in (0..=top).rev() {
+        // F₂ₖ, F₂ₖ₊₁
+        let double = mul(&f, &sub(&add(&f_next, &f_next), &f).unwrap());
+        let double_next = add(&mul(&f, &f), &mul(&f_next, &f_next));
+
+        if n >> bit & 1 == 1 {
+            f_next = add(&double, &double_next);
+            f = double_next;
+        } else {
+            f = double;
+            f_next = double_next;
+        }
+    }
+
+    (f, f_next)
+}
+
+/// Computes `n`-th term of linear recurrence using Kitamasa method.
+///
+/// Recurrence is defined as aᵢ = c₁ ⋅aᵢ₋₁ +c₂ ⋅aᵢ₋₂ +… +cₖ ⋅aᵢ₋ₖ where `coeffs` are
+/// c₁, c₂, …, cₖ and `initial` are a₀, a₁, …, aₖ₋₁.
+///
+/// Potentially CPU, memory intesive.
+///
+/// Returns `PlacesRow` with result or `None` when `coeffs` is empty or its length
+/// differs from `initial` length.
+pub fn linear_recurrence(coeffs: &[PlacesRow], initial: &[PlacesRow], n: u64) -> Option<PlacesRow> {
+    let order = coeffs.len();
+    if order == 0 || order != initial.len() {
+        return None;
+    }
+
+    if n < order as u64 {
+        return Some(initial[n as usize].clone());
+    }
+
+    // xⁿ mod (xᵏ -c₁xᵏ⁻¹ -… -cₖ), coefficients from x⁰
+    let mut poly = vec![Row::nought(); order];
+    poly[0] = Row::unity();
+
+    let top = 63 - n.leading_zeros();
+    for bit in (0..=top).rev() {
+        poly = kitamasa_mul(&poly, &poly, coeffs);
+
+        if n >> bit & 1 == 1 {
+            poly = kitamasa_shift(poly, coeffs);
+        }
+    }
+
+    let term = poly
+        .iter()
+        .zip(initial)
+        .fold(Row::nought(), |acc, (p, a)| add(&acc, &mul(p, a)));
+
+    Some(term)
+}
+
+/// Multiplies `poly1` and `poly2` modulo characteristic polynomial given by `coeffs`.
+fn kitamasa_mul(poly1: &[Row], poly2: &[Row], coeffs: &[Row]) -> Vec<Row> {
+    let order = coeffs.len();
+
+    let mut product = vec![Row::nought(); 2 * order - 1];
+    for (inx1, p1) in poly1.iter().enumerate() {
+        if p1.is_nought() {
+            continue;
+        }
+
+        for (inx2, p2) in poly2.iter().enumerate() {
+            let summand = mul(p1, p2);
+            product[inx1 + inx2] = add(&product[inx1 + inx2], &summand);
+        }
+    }
+
+    // xᵈ = c₁xᵈ⁻¹ +c₂xᵈ⁻² +… +cₖxᵈ⁻ᵏ
+    for deg in (order..2 * order - 1).rev() {
+        let top = core::mem::replace(&mut product[deg], Row::nought());
+        if top.is_nought() {
+            continue;
+        }
+
+        for (inx, c) in coeffs.iter().enumerate() {
+            let summand = mul(&top, c);
+            let deg = deg - inx - 1;
+            product[deg] = add(&product[deg], &summand);
+        }
+    }
+
+    product.truncate(order);
+    product
+}
+
+/// Multiplies `poly` by x modulo characteristic polynomial given by `coeffs`.
+fn kitamasa_shift(mut poly: Vec<Row>, coeffs: &[Row]) -> Vec<Row> {
+    let order = coeffs.len();
+    let top = poly.pop().unwrap();
+    poly.insert(0, Row::nought());
+
+    if !top.is_nought() {
+        for (inx, c) in coeffs.iter().enumerate() {
+            let deg = order - inx - 1;
+            poly[deg] = add(&poly[deg], &mul(&top, c));
+        }
+    }
+
+    poly
+}
+
+/// Combined method allows to compute multiplication and power using shared code.
+///
+/// Space for effecient power computation?
+///   🡺 Inspect log₂ power speed up.
+fn mulmul(row1: &RawRow, row2: &RawRow, times: u16) -> Row {
+    let (mpler, mut mcand) = (row1, row2.clone());
+
+    #[cfg(feature = "one-power-mulmul-support")]
+    if times == 0 {
+        return Row { row: mcand };
+    }
+
+    let mpler_len = mpler.len();
+
+    // intermediate product of `mcand` and `mpler`
+    let mut i_product = Vec::with_capacity(0);
+    // intermediate sum of intermediate products
+    let mut i_sum = Vec::with_capacity(0);
+
+    let mut cntr = 0;
+    loop {
+        let mcand_len = mcand.len();
+
+        // avoids repetetive reallocations
+        // +1 stands for contigent new place
+        i_product.reserve(mcand_len + 1);
+        // avoids repetetive reallocations
+        // places count of product cannot
+        // be greater than sum of places of operands
+        i_sum.reserve(mcand_len + mpler_len);
+
+        #[cfg(test)]
+        let i_product_ptr = i_product.as_ptr();
+
+        #[cfg(test)]
+        let i_sum_ptr = i_sum.as_ptr();
+
+        for offset in 0..mpler_len {
+            product(mpler[offset], &mcand, &mut i_product);
+            addition(&i_product, None, &mut i_sum, offset);
+            i_product.clear();
+        }
+
+        #[cfg(test)]
+        assert!(i_product_ptr == i_product.as_ptr());
+
+        #[cfg(test)]
+        assert!(i_sum_ptr == i_sum.as_ptr());
+
+        cntr += 1;
+        if cntr == times {
+            mcand = i_sum;
+            break;
+        }
+
+        mcand.clear();
+        let swap = mcand;
+        mcand = i_sum;
+        i_sum = swap;
+    }
+
+    // useless when both of factors cannot be nought
+    shrink_to_fit_raw(&mut mcand);
+    Row { row: mcand }
+}
+
+/// Factors having at least this count of places are multiplied by `karatsuba`.
+const KARATSUBA_THRESHOLD: usize = 128;
+
+/// Karatsuba multiplication. Falls back to `convolution` for factors shorter than
+/// `KARATSUBA_THRESHOLD`.
+///
+/// Factors must be without leading zeros.
+fn karatsuba(factor1: &RawRow, factor2: &RawRow) -> RawRow {
+    let (long, short) = if factor1.len() >= factor2.len() {
+        (factor1, factor2)
+    } else {
+        (factor2, factor1)
+    };
+
+    let long_len = long.len();
+    let short_len = short.len();
+
+    if short_len < KARATSUBA_THRESHOLD {
+        if is_nought_raw(short) || is_nought_raw(long) {
+            return nought_raw();
+        }
+
+        return convolution(long, short);
+    }
+
+    let half = long_len / 2;
+    let (long_lo, long_hi) = karatsuba_split(long, half);
+
+    let mut product = vec![0; long_len + short_len];
+
+    // short factor does not reach into high half
+    if short_len <= half {
+        let lo = karatsuba(&long_lo, short);
+        let hi = karatsuba(&long_hi, short);
+
+        addition(&lo, None, &mut product, 0);
+        addition(&hi, None, &mut product, half);
+    } else {
+        let (short_lo, short_hi) = karatsuba_split(short, half);
+
+        // z₁ = (a₁ +a₀)(b₁ +b₀) -z₂ -z₀
+        let z0 = karatsuba(&long_lo, &short_lo);
+        let z2 = karatsuba(&long_hi, &short_hi);
+        let z1 = karatsuba(&add_raw(&long_lo, &long_hi), &add_raw(&short_lo, &short_hi));
+        let z1 = subtraction(&z1, &z2, false).0;
+        let z1 = subtraction(&z1, &z0, false).0;
+
+        addition(&z0, None, &mut product, 0);
+        addition(&z1, None, &mut product, half);
+        addition(&z2, None, &mut product, 2 * half);
+    }
+
+    shrink_to_fit_raw(&mut product);
+    product
+}
+
+/// Schoolbook multiplication accumulating place products before takeover is resolved.
+fn convolution(factor1: &RawRow, factor2: &RawRow) -> RawRow {
+    let mut acc = vec![0u32; factor1.len() + factor2.len()];
+    for (inx1, &num1) in factor1.iter().enumerate() {
+        if num1 == 0 {
+            continue;
+        }
+
+        for (inx2, &num2) in factor2.iter().enumerate() {
+            acc[inx1 + inx2] += (num1 * num2) as u32;
+        }
+    }
+
+    let mut takeover = 0;
+    let mut product = Vec::with_capacity(acc.len());
+    for a in acc {
+        let total = a + takeover;
+        product.push((total % 10) as u8);
+        takeover = total / 10;
+    }
+
+    shrink_to_fit_raw(&mut product);
+    product
+}
+
+/// Splits `row` at `inx` into lower and higher part, both without leading zeros.
+fn karatsuba_split(row: &RawRow, inx: usize) -> (RawRow, RawRow) {
+    let mut lo = row[..inx].to_vec();
+    truncate_leading_raw(&mut lo, 0, 1);
+    let hi = row[inx..].to_vec();
+
+    (lo, hi)
+}
+
+fn add_raw(addend1: &RawRow, addend2: &RawRow) -> RawRow {
+    let (addend, augend) = if addend1.len() > addend2.len() {
+        (addend1, addend2)
+    } else {
+        (addend2, addend1)
+    };
+
+    let mut sum = Vec::with_capacity(addend.len() + 1);
+    addition(addend, Some(augend), &mut sum, 0);
+    sum
+}
+
+/// Computes product of `mpler` and `mcand`.
+fn product(mpler: u8, mcand: &RawRow, product: &mut RawRow) {
+    let mut takeover = 0;
+
+    // runs in vain for `mpler` = 0
+    //   🡺 inspect possibilities
+    for &num in mcand {
+        // each `prod` can be immediately added to intermediate sum
+        //   🡺 inspect this option
+        let prod = mpler * num;
+        let prod = ones(prod, &mut takeover);
+        product.push(prod);
+    }
+
+    if takeover != 0 {
+        product.push(takeover);
+    }
+}
+
+/// Adds `addend_1` to `sum` or adds `addend_1` and `addend_2` sum into `sum`.
+///
+/// Precise expectations must be upkept when adding 2 addends: sum is assumed to be empty, `addend_1` to be longer or equal of numbers and offset to be `0`.
+fn addition(addend_1: &RawRow, addend_2: Option<&RawRow>, sum: &mut RawRow, offset: usize) {
+    let addend_1_len = addend_1.len();
+
+    let (addend_2_ptr, addend_2_len) = if let Some(addend) = addend_2 {
+        (addend.as_ptr(), addend.len())
+    } else {
+        (sum.as_ptr(), sum.len())
+    };
+
+    let mut takeover = 0;
+    let mut addend_1_inx = 0;
+    let mut addend_2_inx = offset;
+
+    loop {
+        let addend_1_available = addend_1_inx < addend_1_len;
+        if !addend_1_available && takeover == 0 {
+            break;
+        }
+
+        let addend_1_num = if addend_1_available {
+            addend_1[addend_1_inx]
+        } else {
+            0
+        };
+
+        let addend_2_num = if addend_2_inx < addend_2_len {
+            unsafe { addend_2_ptr.offset(addend_2_inx as isize).read() }
+        } else {
+            0
+        };
+
+        let add = ones(addend_2_num + addend_1_num, &mut takeover);
+        if let Some(refer) = sum.get_mut(addend_2_inx) {
+            *refer = add;
+        } else {
+            sum.push(add);
+        }
+
+        addend_1_inx += 1;
+        addend_2_inx += 1;
+    }
+}
+
+/// For difference computation applies precondition minuend ≥ subtrahend.
+/// Returns difference/remainder and ration in order.
+//
+// NOTE: Support for longer subtrahend implies extended guard condition on
+// correction `inx < subtrahend_len && inx < minuend_len`. See feature 'shorter-dividend-support'.
+fn subtraction(minuend: &RawRow, subtrahend: &RawRow, remainder: bool) -> (RawRow, RawRow) {
+    let mut diffrem_populated = false;
+
+    let minuend_len = minuend.len();
+    let subtrahend_len = subtrahend.len();
+
+    let mut diffrem = vec![0; minuend_len];
+    let diffrem_ptr = diffrem.as_ptr();
+    let mut minuend_ptr = minuend.as_ptr();
+
+    let mut ratio = nought_raw();
+    let one = vec![1; 1];
+    let mut takeover;
+    let mut inx;
+    loop {
+        takeover = 0;
+        inx = 0;
+
+        while inx < minuend_len {
+            let s_num = if inx < subtrahend_len {
+                subtrahend[inx]
+            } else if takeover == 0 && diffrem_populated {
+                break;
+            } else {
+                0
+            };
+
+            let mut m_num = unsafe { minuend_ptr.offset(inx as isize).read() };
+
+            let total_s = s_num + takeover;
+            takeover = if m_num < total_s {
+                m_num += 10;
+                1
+            } else {
+                0
+            };
+
+            diffrem[inx] = m_num - total_s;
+            inx += 1;
+        }
+
+        // existing remainder implies _minuend_ exhaustion
+        // thus remainder is one turn more than is correct
+        if takeover == 1 {
+            inx = 0;
+            takeover = 0;
+            while inx < subtrahend_len {
+                let correction = diffrem[inx] + subtrahend[inx];
+                diffrem[inx] = ones(correction, &mut takeover);
+                inx += 1;
+            }
+
+            truncate_leading_raw(&mut diffrem, 9, inx);
+            break;
+        }
+
+        addition(&one, None, &mut ratio, 0);
+
+        if !remainder {
+            break;
+        }
+
+        if !diffrem_populated {
+            minuend_ptr = diffrem_ptr;
+            diffrem_populated = true;
+        }
+    }
+
+    shrink_to_fit_raw(&mut diffrem);
+    (diffrem, ratio)
+}
+
+/// Long division. Each place of `dividend` is brought down into partial remainder
+/// which is then reduced by `subtraction` at most 9 times.
+/// Returns remainder and ratio in order.
+fn division(dividend: &RawRow, divisor: &RawRow) -> (RawRow, RawRow) {
+    let dividend_len = dividend.len();
+
+    let mut ratio = vec![0; dividend_len];
+    let mut rem = nought_raw();
+
+    for inx in (0..dividend_len).rev() {
+        if is_nought_raw(&rem) {
+            rem[0] = dividend[inx];
+        } else {
+            rem.insert(0, dividend[inx]);
+        }
+
+        if let Rel::Lesser(_) = rel_raw(&rem, divisor) {
+            continue;
+        }
+
+        let remratio = subtraction(&rem, divisor, true);
+        rem = remratio.0;
+        ratio[inx] = remratio.1[0];
+    }
+
+    shrink_to_fit_raw(&mut ratio);
+    (rem, ratio)
+}
 
 /// Supports algorithimical decimal row computations.
 /// Solve problem as ones to ones addition.
@@ -863,1055 +6181,5738 @@ fn ones(num: u8, takeover_ref: &mut u8) -> u8 {
     let mut takeover_val = *takeover_ref;
     let total = num + takeover_val;
 
-    takeover_val = total / 10;
-    *takeover_ref = takeover_val;
+    takeover_val = total / 10;
+    *takeover_ref = takeover_val;
+
+    total - takeover_val * 10
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use crate::RawRow;
+
+    fn unity() -> RawRow {
+        [1].to_vec()
+    }
+
+    fn nought() -> RawRow {
+        [0].to_vec()
+    }
+
+    mod placesrow {
+        use crate::Row;
+        use alloc::string::ToString;
+
+        mod new_from_vec {
+            use crate::Row;
+            use alloc::vec;
+
+            #[test]
+            fn basic_test() {
+                let row = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+                let proof = row.clone();
+                let row = Row::new_from_vec(row);
+
+                assert!(row.is_ok());
+                assert_eq!(proof, row.unwrap().row);
+            }
+
+            #[test]
+            fn zero_len_test() {
+                let row = Row::new_from_vec(vec![0; 0]);
+                assert!(row.is_err());
+                assert_eq!(None, row.err().unwrap());
+            }
+
+            #[test]
+            fn unsupported_num_len_index_test() {
+                let row = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0];
+                let row = Row::new_from_vec(row);
+
+                assert!(row.is_err());
+                assert_eq!(Some(10), row.err().unwrap());
+            }
+
+            #[test]
+            fn unsupported_num_0_index_test() {
+                let row = vec![10, 0, 0, 0];
+                let row = Row::new_from_vec(row);
+
+                assert!(row.is_err());
+                assert_eq!(Some(0), row.err().unwrap());
+            }
+
+            #[test]
+            fn leading_zeros_trim_test() {
+                let row = Row::new_from_vec(vec![1, 2, 0, 0]);
+                assert_eq!(&[1, 2], &*row.unwrap().row);
+            }
+
+            #[test]
+            fn zero_reduction_test() {
+                let row = Row::new_from_vec(vec![0, 0, 0, 0]);
+                assert_eq!(&[0], &*row.unwrap().row);
+            }
+        }
+
+        #[test]
+        fn new_from_num_test() {
+            let row = Row::new_from_num(000_1234567890);
+            assert_eq!(&[0, 9, 8, 7, 6, 5, 4, 3, 2, 1], &*row);
+        }
+
+        mod new_from_str {
+            use crate::Row;
+
+            #[test]
+            fn zero_len_test() {
+                let row = Row::new_from_str("");
+                assert!(row.is_err());
+                assert_eq!(None, row.err().unwrap());
+            }
+
+            #[test]
+            fn leading_zeros_trim_test() {
+                let row = Row::new_from_str("0021");
+                assert!(row.is_ok());
+                assert_eq!(&[1, 2], &*row.unwrap().row);
+            }
+
+            #[test]
+            fn zeros_reduction_test() {
+                let row = Row::new_from_str("0000");
+                assert!(row.is_ok());
+                assert_eq!(&[0], &*row.unwrap().row);
+            }
+
+            #[test]
+            fn nondigit_str_test() {
+                let row = Row::new_from_str("0012w123");
+                assert!(row.is_err());
+                let inx = row.err().unwrap();
+                assert!(inx.is_some());
+                assert_eq!(4, inx.unwrap());
+            }
+
+            #[test]
+            fn basic_test() {
+                let row = Row::new_from_str("1234567890");
+                assert!(row.is_ok());
+                assert_eq!(&[0, 9, 8, 7, 6, 5, 4, 3, 2, 1], &*row.unwrap().row);
+            }
+        }
+
+        mod to_number {
+            use crate::Row;
+            use alloc::vec;
+
+            #[test]
+            fn basic_test() {
+                let row = Row::new_from_vec(vec![0, 9, 8, 7, 6, 5, 4, 3, 2, 1]).unwrap();
+                assert_eq!("1234567890", row.to_number().as_str());
+            }
+        }
+
+        use super::{nought, unity};
+        #[test]
+        fn is_unity_test() {
+            let test = Row { row: unity() };
+            assert_eq!(true, test.is_unity());
+        }
+
+        #[test]
+        fn is_nought_test() {
+            let test = Row { row: nought() };
+            assert_eq!(true, test.is_nought());
+        }
+
+        #[test]
+        fn unity_test() {
+            let proof = Row { row: unity() };
+            assert_eq!(proof, Row::unity());
+        }
+
+        #[test]
+        fn nought_test() {
+            let proof = Row { row: nought() };
+            assert_eq!(proof, Row::nought());
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn zero_test() {
+            assert_eq!(&[0], &*Row::zero());
+        }
+
+        #[test]
+        fn to_string_test() {
+            let row = Row::new_from_num(1);
+            assert_eq!("1", row.to_string());
+        }
+
+        #[test]
+        fn from_test() {
+            let row: Row = From::from(123);
+            assert_eq!(&[3, 2, 1], &*row);
+        }
+    }
+
+    mod shrink_to_fit_raw {
+        use crate::shrink_to_fit_raw;
+        use alloc::vec::Vec;
+
+        #[test]
+        fn zero_truncation_test() {
+            let cap = 100;
+            let mut row = Vec::with_capacity(cap);
+            row.push(1);
+            row.push(2);
+            row.push(0);
+
+            shrink_to_fit_raw(&mut row);
+            assert_eq!(&[1, 2], &*row);
+            assert!(row.capacity() < cap);
+        }
+
+        #[test]
+        fn first_preservation_test() {
+            let cap = 100;
+            let mut row = Vec::with_capacity(cap);
+            row.push(0);
+            row.push(0);
+            row.push(0);
+
+            shrink_to_fit_raw(&mut row);
+            assert_eq!(&[0], &*row);
+            assert!(row.capacity() < cap);
+        }
+    }
+
+    mod truncate_leading_raw {
+        use crate::truncate_leading_raw;
+        use alloc::vec;
+
+        #[test]
+        fn basic_test() {
+            let mut row = vec![7, 7, 7, 7, 7];
+            truncate_leading_raw(&mut row, 7, 3);
+            assert_eq!(vec![7, 7, 7,], row);
+        }
+    }
+
+    mod len_without_leading_raw {
+        use crate::len_without_leading_raw;
+        use alloc::vec;
+
+        #[test]
+        fn counting_test() {
+            let count = len_without_leading_raw(&vec![1, 2, 5, 5, 5], 5, 0);
+            assert_eq!(2, count);
+        }
+
+        #[test]
+        fn preservation_test() {
+            let count = len_without_leading_raw(&vec![5, 5, 5, 5], 5, 1);
+            assert_eq!(1, count);
+        }
+
+        #[test]
+        fn no_leading_test() {
+            let count = len_without_leading_raw(&vec![5, 5, 5, 0], 5, 0);
+            assert_eq!(4, count);
+        }
+
+        #[test]
+        fn upto_equal_len_test() {
+            let count = len_without_leading_raw(&vec![5, 5, 5], 5, 3);
+            assert_eq!(3, count);
+        }
+    }
+
+    use crate::{is_nought_raw, is_unity_raw, nought_raw, unity_raw};
+    #[test]
+    fn unity_raw_test() {
+        assert_eq!(unity(), unity_raw());
+    }
+
+    #[test]
+    fn nought_raw_test() {
+        assert_eq!(nought(), nought_raw());
+    }
+
+    #[test]
+    fn is_unity_raw_test() {
+        assert_eq!(true, is_unity_raw(&unity()));
+    }
+
+    #[test]
+    fn is_nought_raw_test() {
+        assert_eq!(true, is_nought_raw(&nought()));
+    }
+
+    mod is_one_raw_test {
+        use crate::is_one_raw;
+
+        #[test]
+        fn is() {
+            let test = [3].to_vec();
+            assert_eq!(true, is_one_raw(&test, 3));
+        }
+
+        #[test]
+        fn different() {
+            let test = [3].to_vec();
+            assert_eq!(false, is_one_raw(&test, 4));
+        }
+
+        #[test]
+        fn longer() {
+            let test = [3, 3].to_vec();
+            assert_eq!(false, is_one_raw(&test, 3));
+        }
+    }
+
+    mod from_digit {
+        extern crate std;
+
+        use crate::from_digit;
+        use alloc::format;
+        use alloc::string::{String, ToString};
+        use std::panic::catch_unwind;
+
+        #[test]
+        fn basic_test() {
+            for proof in 0..=9 {
+                let test = from_digit(proof.to_string().chars().next().unwrap());
+                assert_eq!(proof, test);
+            }
+        }
+
+        #[test]
+        fn unsupported_char_test() {
+            let uc = ['0' as u8 - 1, '9' as u8 + 1];
+
+            for c in uc {
+                let c = c as char;
+
+                let proof = format!("Unsupported char `{c}` conversion.");
+                let catch = catch_unwind(|| from_digit(c));
+
+                assert!(catch.is_err());
+                let err = catch.unwrap_err().downcast::<String>().unwrap();
+                assert_eq!(proof, *err);
+            }
+        }
+    }
+
+    mod to_digit {
+        use crate::to_digit;
+        use alloc::string::ToString;
+
+        #[test]
+        fn basic_test() {
+            for n in 0..=9 {
+                let test = to_digit(n);
+                let proof = n.to_string().chars().next().unwrap();
+                assert_eq!(proof, test);
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "Only number < 10 supported.")]
+        fn less_than_10_support_only_test() {
+            to_digit(10);
+        }
+    }
+
+    mod ord_of_mag {
+
+        use crate::{ord_of_mag, Oom, OomKind, PlacesRow, Row};
+
+        const PROOF: &str = "3162277660168379331998893544432718533719555139325216826857504852792594438639238221344248108379300295187347284152840055148548856030453880014690519596700153903344921657179259940659150153474113339484124085316929577090471576461044369257879062037808609941828371711548406328552999118596824564203326961604691314336128949791890266529543612676178781350061388186278580463683134952478031143769334671973819513185678403231241795402218308045872844614600253577579702828644029024407977896034543989163349222652612067792651676031048436697793756926155720500369894909469421850007358348844643882731109289109042348054235653403907274019786543725939641726001306990000955784463109626790694418336130181302894541703315807731626386395193793704654765220632063686587197822049312426053454111609356979828132452297000798883523759585328579251362964686511497675217123459559238039375625125369855194955325099947038843990336466165470647234999796132343403021857052187836676345789510732982875157945215771652139626324438399018484560935762602";
+
+        #[test]
+        fn consts_test() {
+            use crate::SQUARE_ROOT_TEN_COMPARATOR;
+
+            assert_eq!(1000, SQUARE_ROOT_TEN_COMPARATOR.len());
+            assert_eq!(PROOF, SQUARE_ROOT_TEN_COMPARATOR);
+        }
+
+        #[test]
+        fn nought_test() {
+            let r = Row::nought();
+            let oom = unsafe { core::mem::transmute::<u8, OomKind>(u8::MAX) };
+
+            assert_eq!(Oom::Undefined, ord_of_mag(&r, oom));
+        }
+
+        #[test]
+        fn readme_sample_test() {
+            let number_1 = PlacesRow::new_from_num(3162277660168379331998893544432);
+            let number_2 = PlacesRow::new_from_num(3162277660168379331998893544433);
+
+            assert_eq!(Oom::Precise(30), ord_of_mag(&number_1, OomKind::Strict));
+            assert_eq!(Oom::Precise(31), ord_of_mag(&number_2, OomKind::Strict));
+            assert_eq!(Oom::Precise(30), ord_of_mag(&number_2, OomKind::Loose));
+        }
+
+        mod strict {
+            use alloc::string::String;
+
+            use super::PROOF;
+            use crate::{ord_of_mag, Oom, OomKind::Strict, Row};
+
+            #[test]
+            fn universal_test() {
+                #[rustfmt::skip]
+                // lesser-shorter-greater triplets
+                let values = [
+                    (2,0), (3, 0), (4, 1),
+                    (30, 1), (31, 1), (32, 2),                    
+                    (315, 2), (316, 2), (317, 3), 
+                    (3161, 3), (3162, 3), (3163, 4),                       
+                    (316_227_765, 8), (316_227_766, 8), (316_227_767, 9),                                   
+                ];
+
+                for v in values {
+                    let r = Row::new_from_num(v.0);
+                    let o = ord_of_mag(&r, Strict);
+
+                    assert_eq!(Oom::Precise(v.1), o, "{:?}", v);
+                }
+            }
+
+            #[test]
+            fn full_precision_test() {
+                let r = Row::new_from_str(PROOF).unwrap();
+                let o = ord_of_mag(&r, Strict);
+
+                // `PROOF` is truncated √10 ⋅10⁹⁹⁹
+                assert_eq!(Oom::Precise(PROOF.len() - 1), o);
+            }
+
+            #[test]
+            fn behind_precision_test() {
+                // √10 ⋅10¹⁰⁰¹ = `PROOF` 03…
+                #[rustfmt::skip]
+                let values = [
+                    ("0", 1000), ("1", 1001),
+                    ("02", 1001), ("03", 1001), ("04", 1002),
+                ];
+
+                for v in values {
+                    let mut proof = String::from(PROOF);
+                    proof.push_str(v.0);
+
+                    let r = Row::new_from_str(proof.as_str()).unwrap();
+                    let o = ord_of_mag(&r, Strict);
+
+                    assert_eq!(Oom::Precise(v.1), o, "{:?}", v);
+                }
+            }
+
+            #[test]
+            fn behind_precision_greater_test() {
+                let mut proof = String::from(PROOF);
+                proof.pop();
+                proof.push('3');
+                proof.push('0');
+
+                let r = Row::new_from_str(proof.as_str()).unwrap();
+                let o = ord_of_mag(&r, Strict);
+
+                assert_eq!(Oom::Precise(1001), o);
+            }
+        }
+
+        mod loose {
+            use crate::{ord_of_mag, Oom, OomKind::Loose, Row};
+
+            #[test]
+            fn universal_test() {
+                #[rustfmt::skip]
+                let values = [                    
+                    // lesser-equal-greater triplet
+                    (4, 0)    , (5, 1)     , (6, 1),                    
+                    // lesser-longer-greater triplets
+                    (40, 1)   , (50, 2)    , (60, 2),                                                                                                                        
+                    (4000, 3) , (5000, 4)  , (6000, 4)                                                                                                                                                                
+                ];
+
+                for v in values {
+                    let r = Row::new_from_num(v.0);
+                    let o = ord_of_mag(&r, Loose);
+
+                    assert_eq!(Oom::Precise(v.1), o, "{:?}", v);
+                }
+            }
+        }
+    }
+
+    // Relational comparison.
+    mod rel {
+        use crate::{rel, Rel, Row};
+
+        #[test]
+        fn basic_test() {
+            let num = Row::new_from_num(155);
+            let comparand = Row::new_from_num(155);
+
+            assert_eq!(Rel::Equal, rel(&num, &comparand));
+        }
+    }
+
+    mod rel_raw {
+
+        use crate::{rel_raw, Rel, Row};
+
+        #[test]
+        fn longer_test() {
+            let num = Row::new_from_num(11).row;
+            let comparand = Row::new_from_num(9).row;
+
+            let proof = Rel::Greater(Some((2, 1, 1)));
+            assert_eq!(proof, rel_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn shorter_test() {
+            let num = Row::new_from_num(9).row;
+            let comparand = Row::new_from_num(10).row;
+
+            let proof = Rel::Lesser(Some((1, 2, 1)));
+            assert_eq!(proof, rel_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn greater_test() {
+            let num_num = 1234567899;
+            let cpd_num = 1234567890;
+
+            let num = Row::new_from_num(num_num).row;
+            let comparand = Row::new_from_num(cpd_num).row;
+
+            assert_eq!(Rel::Greater(None), rel_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn equal_test() {
+            let num = Row::new_from_num(1234567890);
+            assert_eq!(Rel::Equal, rel_raw(&num.row, &num.row));
+        }
+
+        #[test]
+        fn lesser_test() {
+            let num_num = 1234567890;
+            let cpd_num = 1234567899;
+
+            let num = Row::new_from_num(num_num).row;
+            let comparand = Row::new_from_num(cpd_num).row;
+
+            assert_eq!(Rel::Lesser(None), rel_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn both_nought_test() {
+            let num = Row::new_from_num(0).row;
+
+            assert_eq!(Rel::Equal, rel_raw(&num, &num));
+        }
+    }
+
+    mod rel_dec {
+        use crate::{rel_dec, RelDec, Row};
+
+        #[test]
+        fn basic_test() {
+            let num = Row::new_from_num(9876543210);
+
+            assert_eq!(RelDec::Equal(10), rel_dec(&num, &num));
+        }
+
+        #[test]
+        #[rustfmt::skip]
+        fn readme_sample_test() {
+            let number    = Row::new_from_str("1489754132134687989463132131").unwrap();
+            let comparand = Row::new_from_str(        "48645698946456531371").unwrap();
+            let decrel = rel_dec(&number, &comparand);
+
+            assert_eq!(RelDec::Greater((28, 20, 8)), decrel);
+        }
+    }
+
+    mod rel_dec_raw {
+        use crate::{rel_dec_raw, RelDec, Row};
+
+        #[test]
+        fn equal_test() {
+            let num = Row::new_from_num(9876543210).row;
+
+            assert_eq!(RelDec::Equal(10), rel_dec_raw(&num, &num));
+        }
+
+        #[test]
+        fn lesser_test() {
+            let num = Row::new_from_num(10).row;
+            let comparand = Row::new_from_num(9876543210).row;
+
+            let proof = RelDec::Lesser((2, 10, 8));
+            assert_eq!(proof, rel_dec_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn greater_test() {
+            let num = Row::new_from_num(9876543210).row;
+            let comparand = Row::new_from_num(10).row;
+
+            let proof = RelDec::Greater((10, 2, 8));
+            assert_eq!(proof, rel_dec_raw(&num, &comparand));
+        }
+
+        #[test]
+        fn nought_test() {
+            let num = Row::new_from_num(0).row;
+
+            assert_eq!(RelDec::Equal(0), rel_dec_raw(&num, &num));
+        }
+    }
+
+    // Addition.
+    mod add {
+        use crate::{add, Row};
+
+        #[test]
+        fn basic_test() {
+            let row1 = Row::new_from_num(4);
+            let row2 = Row::new_from_num(5);
+
+            let sum = add(&row1, &row2);
+            assert_eq!(&[9], &*sum.row);
+        }
+
+        #[test]
+        fn left_num_longer_test() {
+            let row1 = Row::new_from_num(10_000);
+            let row2 = Row::new_from_num(5);
+
+            let sum = add(&row1, &row2);
+            assert_eq!(Row::new_from_num(10_005), sum);
+        }
+
+        #[test]
+        fn right_num_longer_test2() {
+            let row1 = Row::new_from_num(5);
+            let row2 = Row::new_from_num(10_000);
+
+            let sum = add(&row1, &row2);
+            assert_eq!(Row::new_from_num(10_005), sum);
+        }
+
+        #[test]
+        fn advanced_test() {
+            let row = Row::new_from_str("680564733841876926926749214863536422910").unwrap();
+
+            let sum = add(&row, &row);
+            assert_eq!("1361129467683753853853498429727072845820", sum.to_number());
+        }
+
+        #[test]
+        fn addend1_nought_test() {
+            let addend1 = Row::nought();
+            let addend2 = Row::new_from_num(4321);
+            let sum = add(&addend1, &addend2);
+            assert_eq!(addend2, sum);
+        }
+
+        #[test]
+        fn addend2_nought_test() {
+            let addend1 = Row::new_from_num(4321);
+            let addend2 = Row::nought();
+            let sum = add(&addend1, &addend2);
+            assert_eq!(addend1, sum);
+        }
+    }
+
+    mod add_shortcut {
+        use crate::{add_shortcut, nought_raw, unity_raw};
+
+        #[test]
+        fn none_nought_test() {
+            assert_eq!(None, add_shortcut(&unity_raw(), &unity_raw()));
+        }
+
+        use alloc::vec;
+        #[test]
+        fn r1_nought_test() {
+            let r1 = nought_raw();
+            let r2 = vec![1, 2, 3, 4];
+            let res = add_shortcut(&r1, &r2);
+            assert_eq!(Some(r2.clone()), res);
+            assert_ne!(r2.as_ptr(), res.unwrap().as_ptr());
+        }
+
+        #[test]
+        fn r2_nought_test() {
+            let r1 = vec![1, 2, 3, 4];
+            let r2 = nought_raw();
+            let res = add_shortcut(&r1, &r2);
+            assert_eq!(Some(r1.clone()), res);
+            assert_ne!(r1.as_ptr(), res.unwrap().as_ptr());
+        }
+    }
+
+    /// Subtraction.
+    mod sub {
+        use crate::{sub, Row};
+
+        #[test]
+        fn lesser_minuend_test() {
+            let minuend = Row::new_from_num(4);
+            let subtrahend = Row::new_from_num(5);
+
+            assert!(sub(&minuend, &subtrahend).is_none());
+        }
+
+        #[test]
+        fn universal_test() {
+            for triplet in [(99, 11, 88), (133, 133, 0), (90, 19, 71), (700, 699, 1)] {
+                let minuend = Row::new_from_num(triplet.0);
+                let subtrahend = Row::new_from_num(triplet.1);
+
+                let proof = Row::new_from_num(triplet.2);
+                let diff = sub(&minuend, &subtrahend);
+                assert!(diff.is_some());
+
+                assert_eq!(proof, diff.unwrap());
+            }
+        }
+    }
+
+    mod sub_shortcut {
+        use crate::{nought_raw, sub_shortcut, Row};
+
+        #[test]
+        fn nought_subtrahend_test() {
+            let minuend = Row::new_from_num(40);
+            let subtrahend = nought_raw();
+
+            let proof = minuend.clone();
+            let test = sub_shortcut(&minuend.row, &subtrahend);
+            assert_eq!(Some(Some(proof)), test);
+        }
+
+        #[test]
+        fn lesser_minuend_test() {
+            let minuend = Row::new_from_num(0).row;
+            let subtrahend = Row::new_from_num(1).row;
+
+            let res = sub_shortcut(&minuend, &subtrahend);
+            assert_eq!(Some(None), res);
+        }
+
+        #[test]
+        fn equal_operands_test() {
+            let minuend = Row::new_from_num(1364).row;
+            let subtrahend = Row::new_from_num(1364).row;
+
+            let proof = Row::nought();
+            let test = sub_shortcut(&minuend, &subtrahend);
+            assert_eq!(Some(Some(proof)), test);
+        }
+
+        #[test]
+        fn greater_minuend_test() {
+            let minuend = Row::new_from_num(2).row;
+            let subtrahend = Row::new_from_num(1).row;
+
+            let res = sub_shortcut(&minuend, &subtrahend);
+            assert_eq!(None, res);
+        }
+    }
+
+    /// Multiplication.
+    mod mul {
+        use crate::{mul, Row};
+
+        #[test]
+        fn basic_test() {
+            let row1 = Row::new_from_num(2);
+            let row2 = Row::new_from_num(3);
+            let prod = mul(&row1, &row2);
+            assert_eq!(&[6], &*prod);
+        }
+
+        #[test]
+        fn row1_nought_test() {
+            let row1 = Row::nought();
+            let row2 = Row::new_from_num(123456789_10111213);
+            let prod = mul(&row1, &row2);
+            let row = &prod.row;
+            assert_eq!(&[0], row.as_slice());
+            assert!(row.capacity() < row2.len());
+        }
+
+        #[test]
+        fn row2_nought_test() {
+            let row1 = Row::new_from_num(123456789_10111213);
+            let row2 = Row::nought();
+            let prod = mul(&row1, &row2);
+            let row = &prod.row;
+            assert_eq!(&[0], row.as_slice());
+            assert!(row.capacity() < row1.len());
+        }
+
+        #[test]
+        fn both_nought_test() {
+            let row1 = Row::nought();
+            let row2 = Row::nought();
+            let prod = mul(&row1, &row2);
+            assert_eq!(&[0], &*prod);
+        }
+
+        #[test]
+        fn advanced_test() {
+            let row = Row::new_from_num(u128::MAX);
+            let prod = mul(&row, &row);
+            let proof =
+                "115792089237316195423570985008687907852589419931798687112530834793049593217025";
+            assert_eq!(proof, prod.to_number());
+        }
+    }
+
+    mod mul_shortcut {
+        use crate::{mul_shortcut, nought_raw, unity_raw, Row};
+
+        #[test]
+        fn factor1_nought_test() {
+            let row1 = nought_raw();
+            let row2 = Row::new_from_num(333_990).row;
+
+            let res = mul_shortcut(&row1, &row2);
+            assert_eq!(Some(row1), res);
+        }
+
+        #[test]
+        fn factor2_nought_test() {
+            let row1 = Row::new_from_num(333_990).row;
+            let row2 = nought_raw();
+
+            let res = mul_shortcut(&row1, &row2);
+            assert_eq!(Some(row2), res);
+        }
+
+        #[test]
+        fn factor1_unity_test() {
+            let row1 = unity_raw();
+            let row2 = Row::new_from_num(333_990).row;
+
+            let res = mul_shortcut(&row1, &row2);
+            assert_eq!(Some(row2), res);
+        }
+
+        #[test]
+        fn factor2_unity_test() {
+            let row1 = Row::new_from_num(333_990).row;
+            let row2 = unity_raw();
+
+            let res = mul_shortcut(&row1, &row2);
+            assert_eq!(Some(row1), res);
+        }
+
+        #[test]
+        fn neither_unity_nor_nought_test() {
+            let row = Row::new_from_num(2).row;
+
+            let res = mul_shortcut(&row, &row);
+            assert_eq!(None, res);
+        }
+    }
+
+    /// For base ≥ 0 and exponent ≥ 0 power can be viewed as nothing more
+    /// than repetetive multiplication with number in question.
+    /// 0º=1, 0¹=1×0, 0²=1×0×0, 0³=1×0×0×0, …
+    /// 1º=1, 1¹=1×1, 1²=1×1×1, 1³=1×1×1×1, …
+    /// 2º=1, 2¹=1×2, 2²=1×2×2, 2³=1×2×2×2, …
+    ///                   ⋮                   
+    mod pow {
+        use crate::{pow, Row};
+
+        #[test]
+        fn basic_test() {
+            let row = Row::new_from_num(2);
+            assert_eq!(&[4], &*pow(&row, 2));
+        }
+
+        #[test]
+        fn advanced_test2() {
+            let proof = Row::new_from_str("88817841970012523233890533447265625").unwrap();
+            let row = Row::new_from_num(25);
+            assert_eq!(proof, pow(&row, 25));
+        }
+
+        #[test]
+        fn advanced_test3() {
+            let proof = Row::new_from_str(
+                "949279437109690919948053832937215463733689853138782229364504479870922851876864",
+            )
+            .unwrap();
+
+            let row = Row::new_from_num(998);
+            assert_eq!(proof, pow(&row, 26));
+        }
+
+        #[test]
+        fn advanced_test4() {
+            let proof = Row::new_from_str(
+                "926336713898529563388567880069503262826159877325124512315660672063305037119488",
+            )
+            .unwrap();
+
+            let row = Row::new_from_num(2);
+            assert_eq!(proof, pow(&row, 259));
+        }
+
+        #[test]
+        #[cfg(feature = "ext-tests")]
+        // readme sample
+        fn advanced_test5() {
+            let row = Row::new_from_num(u128::MAX);
+            let pow = pow(&row, 500);
+            let number = pow.to_number();
+
+            assert!(number.starts_with("8312324609993336522"));
+            assert_eq!(19266, number.len());
+        }
+
+        #[test]
+        fn zero_power_test() {
+            let row = Row::new_from_num(0);
+            let pow = pow(&row, 0);
+            assert_eq!(&[1], &*pow);
+        }
+
+        #[test]
+        fn one_power_test() {
+            let row = Row::new_from_num(3030);
+            let pow = pow(&row, 1);
+            assert_eq!(&[0, 3, 0, 3], &*pow);
+        }
+
+        #[test]
+        fn power_of_nought_test() {
+            let row = Row::new_from_num(0);
+            let pow = pow(&row, 1000);
+            assert_eq!(&[0], &*pow);
+        }
+
+        #[test]
+        fn power_of_one_test() {
+            let row = Row::new_from_num(1);
+            let pow = pow(&row, u16::MAX);
+            assert_eq!(&[1], &*pow);
+        }
+    }
+
+    mod pow_shortcut {
+        use super::nought;
+        use crate::{pow_shortcut, Row};
+
+        #[test]
+        fn zero_power_test() {
+            let row = nought();
+            let pow = pow_shortcut(&row, 0);
+            assert_eq!(Some(Row::unity()), pow);
+        }
+
+        #[test]
+        fn power_of_nought_test() {
+            let row = Row::nought();
+            let pow = pow_shortcut(&row.row, 1000);
+            assert_eq!(Some(row), pow);
+        }
+
+        #[test]
+        fn one_power_test() {
+            let row = Row::new_from_num(3030);
+            let pow = pow_shortcut(&row.row, 1);
+            assert_eq!(Some(row), pow);
+        }
+
+        #[test]
+        fn power_of_one_test() {
+            let row = Row::unity();
+            let pow = pow_shortcut(&row.row, u16::MAX);
+            assert_eq!(Some(row), pow);
+        }
+    }
+
+    /// Division with remainder.
+    mod divrem {
+        use crate::{add, divrem, mul, pow, rel, Rel, Row};
+
+        #[test]
+        fn nought_divisor_test() {
+            let dividend = Row::new_from_num(1);
+            let divisor = Row::new_from_num(0);
+
+            let ratrem = divrem(&dividend, &divisor);
+            assert!(ratrem.is_none());
+        }
+
+        #[test]
+        fn shorter_dividend_test() {
+            let dividend = Row::new_from_num(99);
+            let divisor = Row::new_from_num(999);
+
+            let ratrem = divrem(&dividend, &divisor);
+            assert!(ratrem.is_some());
+
+            let ratrem = ratrem.unwrap();
+
+            assert_eq!(Row::nought(), ratrem.0);
+            assert_eq!(dividend, ratrem.1);
+        }
+
+        #[test]
+        fn universal_test() {
+            for quadruplet in [
+                (0, 100, 0, 0),
+                (99, 11, 9, 0),
+                (133, 133, 1, 0),
+                (90, 19, 4, 14),
+                (700, 699, 1, 1),
+                (700, 70, 10, 0),
+            ] {
+                let dividend = Row::new_from_num(quadruplet.0);
+                let divisor = Row::new_from_num(quadruplet.1);
+
+                let ratio = Row::new_from_num(quadruplet.2);
+                let remainder = Row::new_from_num(quadruplet.3);
+                let ratrem = divrem(&dividend, &divisor);
+
+                assert!(ratrem.is_some());
+                let ratrem = ratrem.unwrap();
+
+                assert_eq!(ratio, ratrem.0);
+                assert_eq!(remainder, ratrem.1);
+            }
+        }
+
+        // repeated subtraction would take about 10¹⁰⁰⁰ ÷divisor steps
+        #[test]
+        fn long_dividend_test() {
+            let dividend = add(&pow(&Row::new_from_num(10), 1_000), &Row::new_from_num(3));
+
+            for divisor in [7u128, 1_000_000_007, 999_999_999_999] {
+                let divisor = Row::new_from_num(divisor);
+                let (ratio, rem) = divrem(&dividend, &divisor).unwrap();
+
+                assert!(matches!(rel(&rem, &divisor), Rel::Lesser(_)));
+                assert_eq!(dividend, add(&mul(&ratio, &divisor), &rem));
+            }
+        }
+    }
+
+    mod divrem_shortcut {
+        use crate::{divrem_shortcut, nought_raw, unity_raw, Row};
+
+        #[test]
+        fn nought_divisor_test() {
+            let dividend = nought_raw();
+            let divisor = nought_raw();
+
+            let ratrem = divrem_shortcut(&dividend, &divisor);
+            assert_eq!(Some(None), ratrem);
+        }
+
+        #[test]
+        fn nought_dividend_test() {
+            let dividend = nought_raw();
+            let divisor = Row::new_from_num(4).row;
+
+            let proof = (Row::nought(), Row::nought());
+            let ratrem = divrem_shortcut(&dividend, &divisor);
+            assert_eq!(Some(Some(proof)), ratrem);
+        }
+
+        #[test]
+        fn unity_divisor_test() {
+            let dividend = nought_raw();
+            let divisor = unity_raw();
+
+            let proof = (Row::nought(), Row::nought());
+            let ratrem = divrem_shortcut(&dividend, &divisor);
+            assert_eq!(Some(Some(proof)), ratrem);
+        }
+
+        #[test]
+        fn unity_divisor_test2() {
+            let dividend = Row::new_from_num(334_556);
+            let divisor = unity_raw();
+
+            let proof = (dividend.clone(), Row::nought());
+            let ratrem = divrem_shortcut(&dividend.row, &divisor);
+            assert_eq!(Some(Some(proof)), ratrem);
+        }
+
+        #[test]
+        fn shorter_dividend_test() {
+            let dividend = Row::new_from_num(99);
+            let divisor = Row::new_from_num(999).row;
+
+            let proof = (Row::nought(), dividend.clone());
+            let ratrem = divrem_shortcut(&dividend.row, &divisor);
+            assert_eq!(Some(Some(proof)), ratrem);
+        }
+    }
+
+    mod signedplacesrow {
+        use crate::{Row, SRow, Sign};
+        use alloc::string::ToString;
+
+        #[test]
+        fn new_test() {
+            let num = SRow::new(Sign::Minus, Row::new_from_num(5));
+            assert_eq!(Sign::Minus, num.sign());
+            assert_eq!(&Row::new_from_num(5), num.mag());
+        }
+
+        #[test]
+        fn new_nought_sign_test() {
+            let num = SRow::new(Sign::Minus, Row::nought());
+            assert_eq!(Sign::Plus, num.sign());
+            assert_eq!(SRow::nought(), num);
+        }
+
+        #[test]
+        fn new_from_num_test() {
+            let num = SRow::new_from_num(-1234);
+            assert_eq!(Sign::Minus, num.sign());
+            assert_eq!(&[4, 3, 2, 1], &**num.mag());
+
+            let num = SRow::new_from_num(i128::MIN);
+            assert_eq!("-170141183460469231731687303715884105728", num.to_number());
+        }
+
+        mod new_from_str {
+            use crate::{Row, SRow, Sign};
+
+            #[test]
+            fn basic_test() {
+                for (s, sign, mag) in [
+                    ("123", Sign::Plus, 123u128),
+                    ("+123", Sign::Plus, 123),
+                    ("-123", Sign::Minus, 123),
+                    ("-000", Sign::Plus, 0),
+                    ("+0021", Sign::Plus, 21),
+                ] {
+                    let proof = SRow::new(sign, Row::new_from_num(mag));
+                    assert_eq!(Ok(proof), SRow::new_from_str(s), "{s}");
+                }
+            }
+
+            #[test]
+            fn zero_len_test() {
+                assert_eq!(Err(None), SRow::new_from_str(""));
+                assert_eq!(Err(None), SRow::new_from_str("-"));
+                assert_eq!(Err(None), SRow::new_from_str("+"));
+            }
+
+            #[test]
+            fn nondigit_str_test() {
+                assert_eq!(Err(Some(4)), SRow::new_from_str("-001w1"));
+                assert_eq!(Err(Some(1)), SRow::new_from_str("--1"));
+                assert_eq!(Err(Some(1)), SRow::new_from_str("1-"));
+            }
+        }
+
+        #[test]
+        fn to_number_test() {
+            assert_eq!("-1234567890", SRow::new_from_num(-1234567890).to_number());
+            assert_eq!("1234567890", SRow::new_from_num(1234567890).to_number());
+            assert_eq!("0", SRow::nought().to_number());
+        }
+
+        #[test]
+        fn abs_test() {
+            assert_eq!(SRow::new_from_num(7), SRow::new_from_num(-7).abs());
+            assert_eq!(SRow::new_from_num(7), SRow::new_from_num(7).abs());
+        }
+
+        #[test]
+        fn signum_test() {
+            assert_eq!(-1, SRow::new_from_num(-7).signum());
+            assert_eq!(0, SRow::nought().signum());
+            assert_eq!(1, SRow::new_from_num(7).signum());
+        }
+
+        #[test]
+        fn neg_test() {
+            assert_eq!(SRow::new_from_num(-7), SRow::new_from_num(7).neg());
+            assert_eq!(SRow::new_from_num(7), SRow::new_from_num(-7).neg());
+            assert_eq!(SRow::nought(), SRow::nought().neg());
+        }
+
+        #[test]
+        fn is_unity_test() {
+            assert!(SRow::unity().is_unity());
+            assert!(!SRow::new_from_num(-1).is_unity());
+        }
+
+        #[test]
+        fn is_nought_test() {
+            assert!(SRow::nought().is_nought());
+            assert!(!SRow::unity().is_nought());
+        }
+
+        #[test]
+        fn to_string_test() {
+            assert_eq!("-1", SRow::new_from_num(-1).to_string());
+        }
+
+        #[test]
+        fn from_test() {
+            let num: SRow = From::from(-123);
+            assert_eq!(SRow::new_from_num(-123), num);
+
+            let num: SRow = From::from(Row::new_from_num(123));
+            assert_eq!(SRow::new_from_num(123), num);
+        }
+    }
+
+    mod rel_signed {
+        use crate::{rel_signed, Rel, SRow};
+
+        #[test]
+        fn basic_test() {
+            let rel = |a, b| rel_signed(&SRow::new_from_num(a), &SRow::new_from_num(b));
+
+            assert_eq!(Rel::Greater(None), rel(1, -1));
+            assert_eq!(Rel::Lesser(None), rel(-1, 1));
+            assert_eq!(Rel::Lesser(None), rel(-5, -3));
+            assert_eq!(Rel::Greater(Some((1, 2, 1))), rel(-5, -30));
+            assert_eq!(Rel::Lesser(Some((1, 2, 1))), rel(5, 30));
+            assert_eq!(Rel::Equal, rel(-5, -5));
+            assert_eq!(Rel::Greater(None), rel(0, -5));
+        }
+    }
+
+    mod signed_arithmetic {
+        use crate::{
+            add_signed, divrem_euclid, divrem_signed, mul_signed, pow_signed, sub_signed, Row, SRow,
+        };
+
+        const NUMS: [i128; 11] = [-1_000_003, -256, -17, -3, -1, 0, 1, 3, 17, 256, 999_999];
+
+        #[test]
+        fn add_test() {
+            for a in NUMS {
+                for b in NUMS {
+                    let res = add_signed(&SRow::new_from_num(a), &SRow::new_from_num(b));
+                    assert_eq!(SRow::new_from_num(a + b), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn sub_test() {
+            for a in NUMS {
+                for b in NUMS {
+                    let res = sub_signed(&SRow::new_from_num(a), &SRow::new_from_num(b));
+                    assert_eq!(SRow::new_from_num(a - b), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn mul_test() {
+            for a in NUMS {
+                for b in NUMS {
+                    let res = mul_signed(&SRow::new_from_num(a), &SRow::new_from_num(b));
+                    assert_eq!(SRow::new_from_num(a * b), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn pow_test() {
+            for a in NUMS {
+                for p in 0..5 {
+                    let res = pow_signed(&SRow::new_from_num(a), p);
+                    assert_eq!(SRow::new_from_num(a.pow(p as u32)), res, "{a} {p}");
+                }
+            }
+        }
+
+        #[test]
+        fn divrem_test() {
+            for a in NUMS {
+                for b in NUMS.into_iter().filter(|&b| b != 0) {
+                    let proof = (SRow::new_from_num(a / b), SRow::new_from_num(a % b));
+                    let res = divrem_signed(&SRow::new_from_num(a), &SRow::new_from_num(b));
+                    assert_eq!(Some(proof), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn divrem_euclid_test() {
+            for a in NUMS {
+                for b in NUMS.into_iter().filter(|&b| b != 0) {
+                    let proof = (
+                        SRow::new_from_num(a.div_euclid(b)),
+                        Row::new_from_num(a.rem_euclid(b) as u128),
+                    );
+                    let res = divrem_euclid(&SRow::new_from_num(a), &SRow::new_from_num(b));
+                    assert_eq!(Some(proof), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn nought_divisor_test() {
+            let num = SRow::new_from_num(-5);
+            assert_eq!(None, divrem_signed(&num, &SRow::nought()));
+            assert_eq!(None, divrem_euclid(&num, &SRow::nought()));
+        }
+    }
+
+    mod ratio {
+        use crate::{Ratio, Row, SRow};
+        use alloc::string::ToString;
+
+        fn ratio(num: i128, den: u128) -> Ratio {
+            Ratio::new_from_num(num, den).unwrap()
+        }
+
+        #[test]
+        fn new_test() {
+            let r = Ratio::new(SRow::new_from_num(-6), Row::new_from_num(8)).unwrap();
+            assert_eq!(&SRow::new_from_num(-3), r.numer());
+            assert_eq!(&Row::new_from_num(4), r.denom());
+
+            let r = ratio(0, 7);
+            assert_eq!(&Row::unity(), r.denom());
+        }
+
+        #[test]
+        fn nought_den_test() {
+            assert_eq!(None, Ratio::new(SRow::unity(), Row::nought()));
+        }
+
+        mod new_from_str {
+            use crate::Ratio;
+
+            #[test]
+            fn basic_test() {
+                for (s, num, den) in [
+                    ("12", 12i128, 1u128),
+                    ("-12", -12, 1),
+                    ("3/4", 3, 4),
+                    ("-6/8", -6, 8),
+                    ("+0/5", 0, 1),
+                    ("0.125", 1, 8),
+                    ("-1.50", -3, 2),
+                    ("-0.5", -1, 2),
+                    ("007.000", 7, 1),
+                ] {
+                    assert_eq!(
+                        Ratio::new_from_num(num, den).ok_or(None),
+                        Ratio::new_from_str(s),
+                        "{s}"
+                    );
+                }
+            }
+
+            #[test]
+            fn error_test() {
+                for (s, err) in [
+                    ("", None),
+                    ("1/0", None),
+                    ("1/", None),
+                    ("1.", None),
+                    (".5", None),
+                    ("1/x", Some(2)),
+                    ("1/-2", Some(2)),
+                    ("1.2.3", Some(3)),
+                    ("-1w/2", Some(2)),
+                ] {
+                    assert_eq!(Err(err), Ratio::new_from_str(s), "{s}");
+                }
+            }
+        }
+
+        #[test]
+        fn to_number_test() {
+            assert_eq!("-3/4", ratio(-6, 8).to_number());
+            assert_eq!("5", ratio(10, 2).to_number());
+            assert_eq!("0", ratio(0, 2).to_string());
+        }
+
+        #[test]
+        fn abs_neg_recip_test() {
+            assert_eq!(ratio(3, 4), ratio(-3, 4).abs());
+            assert_eq!(ratio(-3, 4), ratio(3, 4).neg());
+            assert_eq!(Some(ratio(-4, 3)), ratio(-3, 4).recip());
+            assert_eq!(None, ratio(0, 4).recip());
+        }
+
+        #[test]
+        fn predicates_test() {
+            assert_eq!(-1, ratio(-3, 4).signum());
+            assert!(ratio(8, 4).is_integer());
+            assert!(!ratio(3, 4).is_integer());
+            assert!(ratio(0, 4).is_nought());
+        }
+
+        #[test]
+        fn rounding_test() {
+            // value, floor, ceil, trunc, round
+            for (num, den, floor, ceil, trunc, round) in [
+                (7i128, 2u128, 3i128, 4i128, 3i128, 4i128),
+                (-7, 2, -4, -3, -3, -4),
+                (5, 3, 1, 2, 1, 2),
+                (-5, 3, -2, -1, -1, -2),
+                (4, 3, 1, 2, 1, 1),
+                (-4, 3, -2, -1, -1, -1),
+                (6, 3, 2, 2, 2, 2),
+                (0, 1, 0, 0, 0, 0),
+            ] {
+                let r = ratio(num, den);
+                assert_eq!(SRow::new_from_num(floor), r.floor(), "{num}/{den}");
+                assert_eq!(SRow::new_from_num(ceil), r.ceil(), "{num}/{den}");
+                assert_eq!(SRow::new_from_num(trunc), r.trunc(), "{num}/{den}");
+                assert_eq!(SRow::new_from_num(round), r.round(), "{num}/{den}");
+            }
+        }
+
+        #[test]
+        fn from_test() {
+            let r: Ratio = From::from(SRow::new_from_num(-5));
+            assert_eq!(ratio(-5, 1), r);
+
+            let r: Ratio = From::from(Row::new_from_num(5));
+            assert_eq!(ratio(5, 1), r);
+        }
+    }
+
+    mod ratio_arithmetic {
+        use crate::{add_ratio, div_ratio, mul_ratio, pow_ratio, rel_ratio, sub_ratio, Ratio, Rel};
+
+        fn ratio(num: i128, den: u128) -> Ratio {
+            Ratio::new_from_num(num, den).unwrap()
+        }
+
+        const RATIOS: [(i128, u128); 8] = [
+            (-7, 3),
+            (-1, 2),
+            (0, 1),
+            (1, 6),
+            (2, 3),
+            (5, 4),
+            (9, 1),
+            (-12, 5),
+        ];
+
+        #[test]
+        fn add_sub_test() {
+            for (a, b) in RATIOS {
+                for (c, d) in RATIOS {
+                    let (num, den) = (a * d as i128, b * d);
+                    let proof = ratio(num + c * b as i128, den);
+                    assert_eq!(
+                        proof,
+                        add_ratio(&ratio(a, b), &ratio(c, d)),
+                        "{a}/{b} {c}/{d}"
+                    );
+
+                    let proof = ratio(num - c * b as i128, den);
+                    assert_eq!(
+                        proof,
+                        sub_ratio(&ratio(a, b), &ratio(c, d)),
+                        "{a}/{b} {c}/{d}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn mul_div_test() {
+            for (a, b) in RATIOS {
+                for (c, d) in RATIOS {
+                    let proof = ratio(a * c, b * d);
+                    assert_eq!(
+                        proof,
+                        mul_ratio(&ratio(a, b), &ratio(c, d)),
+                        "{a}/{b} {c}/{d}"
+                    );
+
+                    let proof = match c {
+                        0 => None,
+                        _ => Some(ratio(a * d as i128 * c.signum(), b * c.unsigned_abs())),
+                    };
+                    assert_eq!(
+                        proof,
+                        div_ratio(&ratio(a, b), &ratio(c, d)),
+                        "{a}/{b} {c}/{d}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn rel_test() {
+            for (a, b) in RATIOS {
+                for (c, d) in RATIOS {
+                    let proof = match (a * d as i128).cmp(&(c * b as i128)) {
+                        core::cmp::Ordering::Greater => Rel::Greater(None),
+                        core::cmp::Ordering::Equal => Rel::Equal,
+                        core::cmp::Ordering::Less => Rel::Lesser(None),
+                    };
+                    assert_eq!(
+                        proof,
+                        rel_ratio(&ratio(a, b), &ratio(c, d)),
+                        "{a}/{b} {c}/{d}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn pow_test() {
+            assert_eq!(Some(ratio(-8, 27)), pow_ratio(&ratio(-2, 3), 3));
+            assert_eq!(Some(ratio(9, 4)), pow_ratio(&ratio(-2, 3), -2));
+            assert_eq!(Some(ratio(-27, 8)), pow_ratio(&ratio(-2, 3), -3));
+            assert_eq!(Some(ratio(1, 1)), pow_ratio(&ratio(-2, 3), 0));
+            assert_eq!(Some(ratio(1, 1)), pow_ratio(&ratio(0, 1), 0));
+            assert_eq!(Some(ratio(0, 1)), pow_ratio(&ratio(0, 1), 2));
+            assert_eq!(None, pow_ratio(&ratio(0, 1), -1));
+        }
+    }
+
+    mod round_ratio {
+        use crate::{round_ratio, Rounding, Row, Sign};
+
+        #[test]
+        fn universal_test() {
+            // ratio ⋅10, Up, Down, Ceiling, Floor, HalfUp, HalfDown, HalfEven
+            for (num, ratios) in [
+                (55i128, [6i128, 5, 6, 5, 6, 5, 6]),
+                (25, [3, 2, 3, 2, 3, 2, 2]),
+                (16, [2, 1, 2, 1, 2, 2, 2]),
+                (11, [2, 1, 2, 1, 1, 1, 1]),
+                (10, [1, 1, 1, 1, 1, 1, 1]),
+                (-10, [-1, -1, -1, -1, -1, -1, -1]),
+                (-11, [-2, -1, -1, -2, -1, -1, -1]),
+                (-16, [-2, -1, -1, -2, -2, -2, -2]),
+                (-25, [-3, -2, -2, -3, -3, -2, -2]),
+                (-55, [-6, -5, -5, -6, -6, -5, -6]),
+            ] {
+                let modes = [
+                    Rounding::Up,
+                    Rounding::Down,
+                    Rounding::Ceiling,
+                    Rounding::Floor,
+                    Rounding::HalfUp,
+                    Rounding::HalfDown,
+                    Rounding::HalfEven,
+                ];
+
+                let sign = if num < 0 { Sign::Minus } else { Sign::Plus };
+                let mag = Row::new_from_num(num.unsigned_abs());
+                let den = Row::new_from_num(10);
+
+                for (mode, ratio) in modes.into_iter().zip(ratios) {
+                    let res = round_ratio(sign, &mag, &den, mode);
+                    assert_eq!(
+                        Row::new_from_num(ratio.unsigned_abs()),
+                        res,
+                        "{num} {mode:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    mod bigdecimal {
+        use crate::{BigDecimal, Ratio, Rounding, SRow};
+        use alloc::string::ToString;
+
+        fn dec(s: &str) -> BigDecimal {
+            BigDecimal::new_from_str(s).unwrap()
+        }
+
+        mod new_from_str {
+            use crate::{BigDecimal, SRow};
+
+            #[test]
+            fn basic_test() {
+                for (s, coef, scale) in [
+                    ("-1234.5600", -12345600i128, 4),
+                    ("+1.5", 15, 1),
+                    ("12", 12, 0),
+                    ("0.001", 1, 3),
+                    ("-0.00", 0, 2),
+                    ("007.10", 710, 2),
+                ] {
+                    let proof = BigDecimal::new(SRow::new_from_num(coef), scale);
+                    assert_eq!(Ok(proof), BigDecimal::new_from_str(s), "{s}");
+                }
+            }
+
+            #[test]
+            fn error_test() {
+                for (s, err) in [
+                    ("", None),
+                    ("-", None),
+                    ("1.", None),
+                    (".5", None),
+                    ("1.2.3", Some(3)),
+                    ("-1w.2", Some(2)),
+                    ("1.2e3", Some(3)),
+                ] {
+                    assert_eq!(Err(err), BigDecimal::new_from_str(s), "{s}");
+                }
+            }
+        }
+
+        #[test]
+        fn to_number_test() {
+            for (coef, scale, proof) in [
+                (-12345600i128, 4, "-1234.5600"),
+                (15, 1, "1.5"),
+                (1, 3, "0.001"),
+                (-1, 3, "-0.001"),
+                (0, 2, "0.00"),
+                (12, 0, "12"),
+                (12, -3, "12000"),
+                (0, -3, "0"),
+            ] {
+                let d = BigDecimal::new(SRow::new_from_num(coef), scale);
+                assert_eq!(proof, d.to_number(), "{coef} {scale}");
+            }
+
+            assert_eq!("-1.5", dec("-1.5").to_string());
+        }
+
+        #[test]
+        fn accessors_test() {
+            let d = dec("-12.50");
+            assert_eq!(&SRow::new_from_num(-1250), d.coef());
+            assert_eq!(2, d.scale());
+            assert_eq!(dec("12.50"), d.abs());
+            assert_eq!(dec("12.50"), d.neg());
+            assert_eq!(-1, d.signum());
+            assert!(dec("0.000").is_nought());
+        }
+
+        #[test]
+        fn rescale_test() {
+            for (s, scale, rounding, proof) in [
+                ("1.25", 1, Rounding::HalfEven, "1.2"),
+                ("1.35", 1, Rounding::HalfEven, "1.4"),
+                ("1.25", 1, Rounding::HalfUp, "1.3"),
+                ("-1.25", 1, Rounding::HalfUp, "-1.3"),
+                ("-1.25", 1, Rounding::Ceiling, "-1.2"),
+                ("-1.21", 1, Rounding::Floor, "-1.3"),
+                ("1.29", 1, Rounding::Down, "1.2"),
+                ("1.21", 1, Rounding::Up, "1.3"),
+                ("1.2", 4, Rounding::Down, "1.2000"),
+                ("1250", -2, Rounding::HalfEven, "1200"),
+                ("-0.004", 2, Rounding::HalfUp, "0.00"),
+            ] {
+                assert_eq!(
+                    proof,
+                    dec(s).rescale(scale, rounding).to_number(),
+                    "{s} {scale}"
+                );
+            }
+        }
+
+        #[test]
+        fn normalize_test() {
+            assert_eq!(
+                BigDecimal::new(SRow::new_from_num(-12), -2),
+                dec("-1200.00").normalize()
+            );
+            assert_eq!(dec("1.5"), dec("1.500").normalize());
+            assert_eq!(dec("0"), dec("0.000").normalize());
+
+            let d = BigDecimal::new(SRow::new_from_num(1200), i32::MIN + 1);
+            let proof = BigDecimal::new(SRow::new_from_num(120), i32::MIN);
+            assert_eq!(proof, d.normalize());
+        }
+
+        #[test]
+        fn to_ratio_test() {
+            assert_eq!(
+                Ratio::new_from_num(-1, 8).unwrap(),
+                dec("-0.125").to_ratio()
+            );
+            let d = BigDecimal::new(SRow::new_from_num(12), -2);
+            assert_eq!(Ratio::new_from_num(1200, 1).unwrap(), d.to_ratio());
+        }
+
+        #[test]
+        fn from_test() {
+            let d: BigDecimal = From::from(SRow::new_from_num(-7));
+            assert_eq!(dec("-7"), d);
+        }
+    }
+
+    mod decimal_arithmetic {
+        use crate::{
+            add_decimal, div_decimal, mul_decimal, rel_decimal, sub_decimal, BigDecimal, Rel,
+            Rounding, SRow,
+        };
+
+        fn dec(s: &str) -> BigDecimal {
+            BigDecimal::new_from_str(s).unwrap()
+        }
+
+        #[test]
+        fn add_sub_test() {
+            for (a, b, sum, diff) in [
+                ("1.10", "2.205", "3.305", "-1.105"),
+                ("-1234.5600", "0.44", "-1234.1200", "-1235.0000"),
+                ("0.1", "0.2", "0.3", "-0.1"),
+                ("100", "-0.01", "99.99", "100.01"),
+            ] {
+                assert_eq!(sum, add_decimal(&dec(a), &dec(b)).to_number(), "{a} {b}");
+                assert_eq!(diff, sub_decimal(&dec(a), &dec(b)).to_number(), "{a} {b}");
+            }
+        }
+
+        #[test]
+        fn mul_test() {
+            assert_eq!(
+                "2.42550",
+                mul_decimal(&dec("1.10"), &dec("2.205"))
+                    .unwrap()
+                    .to_number()
+            );
+            assert_eq!(
+                "-0.02",
+                mul_decimal(&dec("-0.1"), &dec("0.2")).unwrap().to_number()
+            );
+        }
+
+        #[test]
+        fn mul_scale_overflow_test() {
+            let one = SRow::unity();
+            let max = BigDecimal::new(one.clone(), i32::MAX);
+            let min = BigDecimal::new(one.clone(), i32::MIN);
+
+            assert_eq!(None, mul_decimal(&max, &BigDecimal::new(one.clone(), 1)));
+            assert_eq!(None, mul_decimal(&min, &BigDecimal::new(one.clone(), -1)));
+
+            let res = mul_decimal(&max, &min).unwrap();
+            assert_eq!(-1, res.scale());
+        }
+
+        #[test]
+        fn div_test() {
+            for (a, b, scale, rounding, proof) in [
+                ("1", "3", 5, Rounding::HalfEven, "0.33333"),
+                ("2", "3", 5, Rounding::HalfEven, "0.66667"),
+                ("-2", "3", 5, Rounding::Down, "-0.66666"),
+                ("-2", "3", 5, Rounding::Floor, "-0.66667"),
+                ("10.00", "0.04", 0, Rounding::HalfUp, "250"),
+                ("1", "8", 2, Rounding::HalfEven, "0.12"),
+                ("1", "8", 2, Rounding::HalfUp, "0.13"),
+                ("12345", "1", -2, Rounding::HalfUp, "12300"),
+            ] {
+                let res = div_decimal(&dec(a), &dec(b), scale, rounding).unwrap();
+                assert_eq!(proof, res.to_number(), "{a} {b}");
+            }
+        }
+
+        #[test]
+        fn nought_divisor_test() {
+            assert_eq!(
+                None,
+                div_decimal(&dec("1"), &dec("0.00"), 2, Rounding::HalfUp)
+            );
+        }
+
+        #[test]
+        fn rel_test() {
+            assert_eq!(Rel::Equal, rel_decimal(&dec("1.50"), &dec("1.5")));
+            assert_eq!(Rel::Greater(None), rel_decimal(&dec("1.51"), &dec("1.5")));
+            assert_eq!(Rel::Lesser(None), rel_decimal(&dec("-1.51"), &dec("-1.5")));
+            assert_eq!(Rel::Greater(None), rel_decimal(&dec("0"), &dec("-0.001")));
+        }
+    }
+
+    mod interval {
+        use crate::{BigDecimal, Interval};
+        use alloc::string::ToString;
+
+        fn dec(s: &str) -> BigDecimal {
+            BigDecimal::new_from_str(s).unwrap()
+        }
+
+        fn interval(lo: &str, hi: &str) -> Interval {
+            Interval::new(dec(lo), dec(hi)).unwrap()
+        }
+
+        #[test]
+        fn new_test() {
+            let i = interval("-1.5", "2.25");
+            assert_eq!(&dec("-1.5"), i.lo());
+            assert_eq!(&dec("2.25"), i.hi());
+
+            assert_eq!(None, Interval::new(dec("2"), dec("1.99")));
+            assert!(Interval::new(dec("2"), dec("2.00")).is_some());
+        }
+
+        #[test]
+        fn point_test() {
+            let i = Interval::point(dec("3.5"));
+            assert_eq!(interval("3.5", "3.5"), i);
+            assert!(i.width().is_nought());
+        }
+
+        #[test]
+        fn to_number_test() {
+            assert_eq!("[-1.5, 2.25]", interval("-1.5", "2.25").to_number());
+            assert_eq!("[0, 1]", interval("0", "1").to_string());
+        }
+
+        #[test]
+        fn width_test() {
+            assert_eq!("3.75", interval("-1.5", "2.25").width().to_number());
+        }
+
+        #[test]
+        fn contains_test() {
+            let i = interval("-1.5", "2.25");
+            for (v, proof) in [
+                ("-1.5", true),
+                ("-1.50", true),
+                ("0", true),
+                ("2.25", true),
+                ("2.2501", false),
+                ("-1.6", false),
+            ] {
+                assert_eq!(proof, i.contains(&dec(v)), "{v}");
+            }
+
+            assert!(i.contains_interval(&interval("-1", "2")));
+            assert!(i.contains_interval(&i));
+            assert!(!i.contains_interval(&interval("-1", "3")));
+        }
+    }
+
+    mod interval_arithmetic {
+        use crate::{
+            add_decimal, add_interval, div_decimal, div_interval, intersect_interval, mul_decimal,
+            mul_interval, sub_decimal, sub_interval, BigDecimal, Interval, Rounding,
+        };
+
+        fn dec(s: &str) -> BigDecimal {
+            BigDecimal::new_from_str(s).unwrap()
+        }
+
+        fn interval(lo: &str, hi: &str) -> Interval {
+            Interval::new(dec(lo), dec(hi)).unwrap()
+        }
+
+        #[test]
+        fn intersect_test() {
+            let a = interval("-1", "2");
+            let b = interval("1.5", "3");
+            assert_eq!(Some(interval("1.5", "2")), intersect_interval(&a, &b));
+            assert_eq!(
+                Some(interval("2", "2")),
+                intersect_interval(&a, &interval("2", "5"))
+            );
+            assert_eq!(None, intersect_interval(&a, &interval("2.1", "5")));
+        }
+
+        #[test]
+        fn basic_test() {
+            let a = interval("1", "2");
+            let b = interval("-3", "0.5");
+
+            assert_eq!(interval("-2", "2.5"), add_interval(&a, &b, 2));
+            assert_eq!(interval("0.5", "5"), sub_interval(&a, &b, 2));
+            assert_eq!(Some(interval("-6", "1.0")), mul_interval(&a, &b, 2));
+
+            let c = interval("3", "4");
+            assert_eq!(Some(interval("0.25", "0.67")), div_interval(&a, &c, 2));
+        }
+
+        #[test]
+        fn outward_rounding_test() {
+            let a = interval("0.123", "0.456");
+            let b = interval("0.001", "0.001");
+
+            assert_eq!(interval("0.12", "0.46"), add_interval(&a, &b, 2));
+            assert_eq!(Some(interval("0.00", "0.01")), mul_interval(&a, &b, 2));
+
+            let a = interval("-1", "1");
+            let c = interval("3", "3");
+            assert_eq!(Some(interval("-0.34", "0.34")), div_interval(&a, &c, 2));
+        }
+
+        #[test]
+        fn div_nought_test() {
+            let a = interval("1", "2");
+            assert_eq!(None, div_interval(&a, &interval("-1", "1"), 2));
+            assert_eq!(None, div_interval(&a, &interval("0", "1"), 2));
+        }
+
+        #[test]
+        fn enclosure_test() {
+            let values = ["-2.5", "-1", "-0.3", "0.07", "1.125", "4"];
+            let intervals = [
+                ("-2.5", "-1"),
+                ("-1", "0.07"),
+                ("0.07", "4"),
+                ("1.125", "1.125"),
+            ];
+
+            for (a_lo, a_hi) in intervals {
+                for (b_lo, b_hi) in intervals {
+                    let (a, b) = (interval(a_lo, a_hi), interval(b_lo, b_hi));
+
+                    let sum = add_interval(&a, &b, 1);
+                    let diff = sub_interval(&a, &b, 1);
+                    let prod = mul_interval(&a, &b, 1).unwrap();
+                    let ratio = div_interval(&a, &b, 1);
+
+                    for x in values.iter().map(|v| dec(v)).filter(|x| a.contains(x)) {
+                        for y in values.iter().map(|v| dec(v)).filter(|y| b.contains(y)) {
+                            assert!(sum.contains(&add_decimal(&x, &y)), "{x} {y}");
+                            assert!(diff.contains(&sub_decimal(&x, &y)), "{x} {y}");
+                            assert!(prod.contains(&mul_decimal(&x, &y).unwrap()), "{x} {y}");
+
+                            if let Some(ratio) = &ratio {
+                                for rounding in [Rounding::Floor, Rounding::Ceiling] {
+                                    let r = div_decimal(&x, &y, 1, rounding).unwrap();
+                                    assert!(ratio.contains(&r), "{x} {y}");
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    mod div_to_places {
+        use crate::{div_to_places, Rounding, Row};
+        use alloc::format;
+
+        #[test]
+        fn basic_test() {
+            let res = div_to_places(
+                &Row::new_from_num(22),
+                &Row::new_from_num(7),
+                6,
+                Rounding::HalfEven,
+            );
+            assert_eq!(Some("3.142857".into()), res);
+        }
+
+        #[test]
+        fn rounding_test() {
+            for (a, b, places, rounding, proof) in [
+                (2u128, 3u128, 4, Rounding::HalfEven, "0.6667"),
+                (2, 3, 4, Rounding::Down, "0.6666"),
+                (1, 8, 2, Rounding::HalfEven, "0.12"),
+                (1, 8, 2, Rounding::HalfUp, "0.13"),
+                (1, 8, 2, Rounding::Floor, "0.12"),
+                (1, 8, 2, Rounding::Ceiling, "0.13"),
+                (7, 2, 0, Rounding::HalfEven, "4"),
+                (5, 2, 0, Rounding::HalfEven, "2"),
+                (0, 3, 3, Rounding::Up, "0.000"),
+                (1, 1, 2, Rounding::Up, "1.00"),
+            ] {
+                let res = div_to_places(
+                    &Row::new_from_num(a),
+                    &Row::new_from_num(b),
+                    places,
+                    rounding,
+                );
+                assert_eq!(Some(proof.into()), res, "{a} {b} {places} {rounding:?}");
+            }
+        }
+
+        #[test]
+        fn universal_test() {
+            for a in 0u128..60 {
+                for b in 1u128..30 {
+                    let scaled = a * 1000 / b;
+                    let proof = format!("{}.{:03}", scaled / 1000, scaled % 1000);
+
+                    let res = div_to_places(
+                        &Row::new_from_num(a),
+                        &Row::new_from_num(b),
+                        3,
+                        Rounding::Down,
+                    );
+                    assert_eq!(Some(proof), res, "{a} {b}");
+                }
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 2²⁰⁰ ÷3¹⁰⁰
+            let a =
+                Row::new_from_str("1606938044258990275541962092341162602522202993782792835301376")
+                    .unwrap();
+            let b = Row::new_from_str("515377520732011331036461129765621272702107522001").unwrap();
+
+            let proof = "3117982410207.941978721488155829839122194539";
+            let res = div_to_places(&a, &b, 30, Rounding::HalfEven);
+            assert_eq!(Some(proof.into()), res);
+        }
+
+        #[test]
+        fn nought_divisor_test() {
+            let res = div_to_places(&Row::unity(), &Row::nought(), 2, Rounding::HalfEven);
+            assert_eq!(None, res);
+        }
+    }
+
+    mod div_to_decimal {
+        use crate::{div_to_decimal, BigDecimal, Rounding, Row};
+
+        #[test]
+        fn basic_test() {
+            let res = div_to_decimal(
+                &Row::new_from_num(22),
+                &Row::new_from_num(7),
+                6,
+                Rounding::HalfEven,
+            )
+            .unwrap();
+
+            assert_eq!(BigDecimal::new_from_str("3.142857").unwrap(), res);
+            assert_eq!(6, res.scale());
+        }
+
+        #[test]
+        fn places_overflow_test() {
+            let (one, three) = (Row::unity(), Row::new_from_num(3));
+            for places in [i32::MAX as u32 + 1, u32::MAX] {
+                let res = div_to_decimal(&one, &three, places, Rounding::HalfEven);
+                assert_eq!(None, res, "{places}");
+            }
+        }
+    }
+
+    mod decimal_expansion {
+        use crate::{decimal_expansion, Row};
+        use alloc::{string::String, vec::Vec};
+
+        fn expansion(num: u128, den: u128) -> String {
+            decimal_expansion(&Row::new_from_num(num), &Row::new_from_num(den))
+                .unwrap()
+                .to_number()
+        }
+
+        #[test]
+        fn basic_test() {
+            for (num, den, proof) in [
+                (1u128, 6u128, "0.1(6)"),
+                (1, 7, "0.(142857)"),
+                (22, 7, "3.(142857)"),
+                (1, 8, "0.125"),
+                (1, 12, "0.08(3)"),
+                (1, 81, "0.(012345679)"),
+                (5, 1, "5"),
+                (10, 4, "2.5"),
+                (0, 3, "0"),
+            ] {
+                assert_eq!(proof, expansion(num, den), "{num} {den}");
+            }
+        }
+
+        #[test]
+        fn parts_test() {
+            let exp = decimal_expansion(&Row::new_from_num(1234), &Row::new_from_num(990)).unwrap();
+            assert_eq!(&Row::unity(), exp.integer());
+            assert_eq!("2", exp.non_repeating());
+            assert_eq!("46", exp.repeating());
+        }
+
+        #[test]
+        fn universal_test() {
+            for num in 0u128..25 {
+                for den in 1u128..100 {
+                    // long division until remainder repeats
+                    let mut rems = Vec::new();
+                    let mut digits = String::new();
+                    let mut rem = num % den;
+                    while rem != 0 && !rems.contains(&rem) {
+                        rems.push(rem);
+                        rem *= 10;
+                        digits.push(char::from(b'0' + (rem / den) as u8));
+                        rem %= den;
+                    }
+
+                    let mut proof = alloc::format!("{}", num / den);
+                    if !digits.is_empty() {
+                        proof.push('.');
+                        match rems.iter().position(|&r| r == rem && rem != 0) {
+                            Some(start) => {
+                                proof.push_str(&digits[..start]);
+                                proof.push('(');
+                                proof.push_str(&digits[start..]);
+                                proof.push(')');
+                            }
+                            None => proof.push_str(&digits),
+                        }
+                    }
+
+                    assert_eq!(proof, expansion(num, den), "{num} {den}");
+                }
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 1 ÷97 has period of 96 places
+            let exp = decimal_expansion(&Row::unity(), &Row::new_from_num(97)).unwrap();
+            assert_eq!(96, exp.repeating().len());
+            assert!(exp.repeating().starts_with("010309278350515463917525773195876288659793814432989690721649484536082474226804123711340206185567"));
+            assert_eq!("", exp.non_repeating());
+        }
+
+        #[test]
+        fn nought_den_test() {
+            assert_eq!(None, decimal_expansion(&Row::unity(), &Row::nought()));
+        }
+
+        #[test]
+        fn period_overflow_test() {
+            // 10 is primitive root of this prime, period has p -1 > usize::MAX places
+            let p = Row::new_from_str("100000000000000000193").unwrap();
+            assert_eq!(None, decimal_expansion(&Row::unity(), &p));
+        }
+    }
+
+    mod floatcontext {
+        use crate::{FloatContext, Rounding};
+
+        #[test]
+        fn new_test() {
+            let ctx = FloatContext::new(12, Rounding::HalfEven).unwrap();
+            assert_eq!(12, ctx.prec());
+            assert_eq!(Rounding::HalfEven, ctx.rounding());
+        }
+
+        #[test]
+        fn nought_prec_test() {
+            assert_eq!(None, FloatContext::new(0, Rounding::HalfEven));
+        }
+    }
+
+    mod bigfloat {
+        use crate::{BigDecimal, BigFloat, FloatContext, Rounding, SRow};
+        use alloc::string::ToString;
+
+        fn float(s: &str) -> BigFloat {
+            BigFloat::new_from_str(s).unwrap()
+        }
+
+        #[test]
+        fn new_test() {
+            let f = BigFloat::new(SRow::new_from_num(-12500), -3).unwrap();
+            assert_eq!(&SRow::new_from_num(-125), f.mant());
+            assert_eq!(-1, f.exp());
+
+            let f = BigFloat::new(SRow::nought(), -3).unwrap();
+            assert_eq!(0, f.exp());
+        }
+
+        #[test]
+        fn exp_overflow_test() {
+            let f = BigFloat::new(SRow::new_from_num(15), i64::MAX).unwrap();
+            assert_eq!(i64::MAX, f.exp());
+
+            assert_eq!(None, BigFloat::new(SRow::new_from_num(10), i64::MAX));
+            assert_eq!(None, BigFloat::new(SRow::new_from_num(-100), i64::MAX - 1));
+        }
+
+        mod new_from_str {
+            use crate::{BigFloat, SRow};
+
+            #[test]
+            fn basic_test() {
+                for (s, mant, exp) in [
+                    ("-12.5", -125i128, -1i64),
+                    ("-1.25e1", -125, -1),
+                    ("125E-1", 125, -1),
+                    ("+0.0012e+3", 12, -1),
+                    ("1200", 12, 2),
+                    ("-0.0e7", 0, 0),
+                    ("1e9223372036854775807", 1, i64::MAX),
+                    ("15e-9223372036854775808", 15, i64::MIN),
+                ] {
+                    let proof = BigFloat::new(SRow::new_from_num(mant), exp).unwrap();
+                    assert_eq!(Ok(proof), BigFloat::new_from_str(s), "{s}");
+                }
+            }
+
+            #[test]
+            fn error_test() {
+                for (s, err) in [
+                    ("", None),
+                    ("1e", None),
+                    ("e5", None),
+                    ("1e-", None),
+                    ("1e99999999999999999999", None),
+                    ("10e9223372036854775807", None),
+                    ("1.5e-9223372036854775808", None),
+                    ("1.5e1x", Some(5)),
+                    ("1.5e-+1", Some(5)),
+                    ("1.x5e1", Some(2)),
+                ] {
+                    assert_eq!(Err(err), BigFloat::new_from_str(s), "{s}");
+                }
+            }
+        }
+
+        #[test]
+        fn to_number_test() {
+            for (s, proof) in [
+                ("-0.000000125", "-1.25e-7"),
+                ("5000", "5e3"),
+                ("123.456", "1.23456e2"),
+                ("0", "0e0"),
+                ("15e9223372036854775807", "1.5e9223372036854775808"),
+                ("15e-9223372036854775808", "1.5e-9223372036854775807"),
+            ] {
+                assert_eq!(proof, float(s).to_number(), "{s}");
+            }
+
+            assert_eq!("-1.5e0", float("-1.5").to_string());
+        }
+
+        #[test]
+        fn unary_test() {
+            assert_eq!(float("1.5"), float("-1.5").abs());
+            assert_eq!(float("1.5"), float("-1.5").neg());
+            assert_eq!(-1, float("-1.5").signum());
+            assert!(float("0e5").is_nought());
+        }
+
+        #[test]
+        fn round_test() {
+            let ctx = FloatContext::new(3, Rounding::HalfEven).unwrap();
+            assert_eq!(Some(float("1.24e0")), float("1.245").round(&ctx));
+            assert_eq!(Some(float("1e3")), float("999.5").round(&ctx));
+            assert_eq!(Some(float("-1.24e10")), float("-12351e6").round(&ctx));
+
+            let ctx = FloatContext::new(2, Rounding::HalfEven).unwrap();
+            assert_eq!(None, float("9.99e9223372036854775807").round(&ctx));
+        }
+
+        #[test]
+        fn decimal_conversion_test() {
+            let d = BigDecimal::new_from_str("-1234.5600").unwrap();
+            let f: BigFloat = From::from(d);
+            assert_eq!(float("-1234.56"), f);
+
+            let d = BigDecimal::new_from_str("-1234.56").unwrap();
+            assert_eq!(Some(d), f.to_decimal());
+            assert_eq!(None, float("1e-3000000000").to_decimal());
+        }
+    }
+
+    mod float_arithmetic {
+        use crate::{
+            add_float, div_float, mul_float, rel_float, sqrt_float, sub_float, BigFloat,
+            FloatContext, Rel, Rounding,
+        };
+
+        fn float(s: &str) -> BigFloat {
+            BigFloat::new_from_str(s).unwrap()
+        }
+
+        #[test]
+        fn universal_test() {
+            // proof by correctly rounded reference implementation, 12 places
+            // sum, difference, product, ratio
+            for (a, b, rounding, proof) in [
+                (
+                    "-9.99999999999999999999",
+                    "-4.4e25",
+                    Rounding::HalfEven,
+                    ["-4.4e25", "4.4e25", "4.4e26", "2.27272727273e-25"],
+                ),
+                (
+                    "-4.4e25",
+                    "-1.000000000000000000005",
+                    Rounding::HalfEven,
+                    ["-4.4e25", "-4.4e25", "4.4e25", "4.4e25"],
+                ),
+                (
+                    "-12345678901.5",
+                    "3",
+                    Rounding::HalfEven,
+                    [
+                        "-1.23456788985e10",
+                        "-1.23456789045e10",
+                        "-3.70370367045e10",
+                        "-4.1152263005e9",
+                    ],
+                ),
+                (
+                    "-7",
+                    "-9.99999999999999999999",
+                    Rounding::HalfEven,
+                    ["-1.7e1", "3e0", "7e1", "7e-1"],
+                ),
+                (
+                    "2.5",
+                    "-0.125",
+                    Rounding::HalfEven,
+                    ["2.375e0", "2.625e0", "-3.125e-1", "-2e1"],
+                ),
+                (
+                    "1",
+                    "2.5",
+                    Rounding::HalfEven,
+                    ["3.5e0", "-1.5e0", "2.5e0", "4e-1"],
+                ),
+                (
+                    "3",
+                    "0.1",
+                    Rounding::HalfEven,
+                    ["3.1e0", "2.9e0", "3e-1", "3e1"],
+                ),
+                (
+                    "1",
+                    "3",
+                    Rounding::HalfEven,
+                    ["4e0", "-2e0", "3e0", "3.33333333333e-1"],
+                ),
+                (
+                    "-7",
+                    "0.0000000000045",
+                    Rounding::HalfUp,
+                    ["-7e0", "-7e0", "-3.15e-11", "-1.55555555556e12"],
+                ),
+                (
+                    "-4.4e25",
+                    "-1.000000000000000000005",
+                    Rounding::HalfUp,
+                    ["-4.4e25", "-4.4e25", "4.4e25", "4.4e25"],
+                ),
+                (
+                    "-0.125",
+                    "-1.000000000000000000005",
+                    Rounding::HalfUp,
+                    ["-1.125e0", "8.75e-1", "1.25e-1", "1.25e-1"],
+                ),
+                (
+                    "7e100",
+                    "-9.99999999999999999999",
+                    Rounding::HalfUp,
+                    ["7e100", "7e100", "-7e101", "-7e99"],
+                ),
+                (
+                    "0.1",
+                    "-9.99999999999999999999",
+                    Rounding::HalfUp,
+                    ["-9.9e0", "1.01e1", "-1e0", "-1e-2"],
+                ),
+                (
+                    "0.0000000000045",
+                    "-1.000000000000000000005",
+                    Rounding::HalfUp,
+                    ["-9.99999999996e-1", "1e0", "-4.5e-12", "-4.5e-12"],
+                ),
+                (
+                    "7e100",
+                    "123456789.987654321",
+                    Rounding::HalfUp,
+                    ["7e100", "7e100", "8.64197529914e108", "5.67000000624e92"],
+                ),
+                (
+                    "123456789.987654321",
+                    "3",
+                    Rounding::HalfUp,
+                    [
+                        "1.23456792988e8",
+                        "1.23456786988e8",
+                        "3.70370369963e8",
+                        "4.11522633292e7",
+                    ],
+                ),
+                (
+                    "123456789.987654321",
+                    "-12345678901.5",
+                    Rounding::Down,
+                    [
+                        "-1.22222221115e10",
+                        "1.24691356914e10",
+                        "-1.52415788739e18",
+                        "-1.00000000787e-2",
+                    ],
+                ),
+                (
+                    "-0.125",
+                    "0.1",
+                    Rounding::Down,
+                    ["-2.5e-2", "-2.25e-1", "-1.25e-2", "-1.25e0"],
+                ),
+                (
+                    "-7",
+                    "3",
+                    Rounding::Down,
+                    ["-4e0", "-1e1", "-2.1e1", "-2.33333333333e0"],
+                ),
+                (
+                    "-4.4e25",
+                    "3",
+                    Rounding::Down,
+                    [
+                        "-4.39999999999e25",
+                        "-4.4e25",
+                        "-1.32e26",
+                        "-1.46666666666e25",
+                    ],
+                ),
+                (
+                    "0.1",
+                    "3",
+                    Rounding::Down,
+                    ["3.1e0", "-2.9e0", "3e-1", "3.33333333333e-2"],
+                ),
+                (
+                    "-0.125",
+                    "123456789.987654321",
+                    Rounding::Down,
+                    [
+                        "1.23456789862e8",
+                        "-1.23456790112e8",
+                        "-1.54320987484e7",
+                        "-1.01250000111e-9",
+                    ],
+                ),
+                (
+                    "3",
+                    "7e100",
+                    Rounding::Down,
+                    [
+                        "7e100",
+                        "-6.99999999999e100",
+                        "2.1e101",
+                        "4.28571428571e-101",
+                    ],
+                ),
+                (
+                    "-12345678901.5",
+                    "-0.125",
+                    Rounding::Down,
+                    [
+                        "-1.23456789016e10",
+                        "-1.23456789013e10",
+                        "1.54320986268e9",
+                        "9.8765431212e10",
+                    ],
+                ),
+                (
+                    "1",
+                    "123456789.987654321",
+                    Rounding::Ceiling,
+                    [
+                        "1.23456790988e8",
+                        "-1.23456788987e8",
+                        "1.23456789988e8",
+                        "8.10000000892e-9",
+                    ],
+                ),
+                (
+                    "-9.99999999999999999999",
+                    "3",
+                    Rounding::Ceiling,
+                    [
+                        "-6.99999999999e0",
+                        "-1.29999999999e1",
+                        "-2.99999999999e1",
+                        "-3.33333333333e0",
+                    ],
+                ),
+                (
+                    "1e-30",
+                    "1e-30",
+                    Rounding::Ceiling,
+                    ["2e-30", "0e0", "1e-60", "1e0"],
+                ),
+                (
+                    "2.5",
+                    "2.5",
+                    Rounding::Ceiling,
+                    ["5e0", "0e0", "6.25e0", "1e0"],
+                ),
+                (
+                    "-7",
+                    "-0.125",
+                    Rounding::Ceiling,
+                    ["-7.125e0", "-6.875e0", "8.75e-1", "5.6e1"],
+                ),
+                (
+                    "-1.000000000000000000005",
+                    "2.5",
+                    Rounding::Ceiling,
+                    ["1.5e0", "-3.5e0", "-2.5e0", "-4e-1"],
+                ),
+                (
+                    "0.0000000000045",
+                    "1",
+                    Rounding::Ceiling,
+                    ["1.00000000001e0", "-9.99999999995e-1", "4.5e-12", "4.5e-12"],
+                ),
+                (
+                    "1",
+                    "7e100",
+                    Rounding::Ceiling,
+                    [
+                        "7.00000000001e100",
+                        "-6.99999999999e100",
+                        "7e100",
+                        "1.42857142858e-101",
+                    ],
+                ),
+                (
+                    "-12345678901.5",
+                    "7e100",
+                    Rounding::Floor,
+                    [
+                        "6.99999999999e100",
+                        "-7.00000000001e100",
+                        "-8.64197523105e110",
+                        "-1.7636684145e-91",
+                    ],
+                ),
+                (
+                    "-9.99999999999999999999",
+                    "-1.000000000000000000005",
+                    Rounding::Floor,
+                    ["-1.1e1", "-9e0", "1e1", "9.99999999999e0"],
+                ),
+                (
+                    "1",
+                    "-1.000000000000000000005",
+                    Rounding::Floor,
+                    ["-5e-21", "2e0", "-1.00000000001e0", "-1e0"],
+                ),
+                (
+                    "-1.000000000000000000005",
+                    "-1.000000000000000000005",
+                    Rounding::Floor,
+                    ["-2.00000000001e0", "0e0", "1e0", "1e0"],
+                ),
+                (
+                    "-1.000000000000000000005",
+                    "-12345678901.5",
+                    Rounding::Floor,
+                    [
+                        "-1.23456789026e10",
+                        "1.23456789004e10",
+                        "1.23456789015e10",
+                        "8.10000007272e-11",
+                    ],
+                ),
+                (
+                    "7e100",
+                    "0.0000000000045",
+                    Rounding::Floor,
+                    ["7e100", "6.99999999999e100", "3.15e89", "1.55555555555e112"],
+                ),
+                (
+                    "123456789.987654321",
+                    "123456789.987654321",
+                    Rounding::Floor,
+                    ["2.46913579975e8", "0e0", "1.5241578994e16", "1e0"],
+                ),
+                (
+                    "-12345678901.5",
+                    "1e-30",
+                    Rounding::Floor,
+                    [
+                        "-1.23456789015e10",
+                        "-1.23456789016e10",
+                        "-1.23456789015e-20",
+                        "-1.23456789015e40",
+                    ],
+                ),
+                (
+                    "7e100",
+                    "1e-30",
+                    Rounding::Up,
+                    ["7.00000000001e100", "7e100", "7e70", "7e130"],
+                ),
+                (
+                    "0.1",
+                    "0.0000000000045",
+                    Rounding::Up,
+                    [
+                        "1.00000000005e-1",
+                        "9.99999999955e-2",
+                        "4.5e-13",
+                        "2.22222222223e10",
+                    ],
+                ),
+                (
+                    "-4.4e25",
+                    "-7",
+                    Rounding::Up,
+                    [
+                        "-4.40000000001e25",
+                        "-4.4e25",
+                        "3.08e26",
+                        "6.28571428572e24",
+                    ],
+                ),
+                (
+                    "2.5",
+                    "1e-30",
+                    Rounding::Up,
+                    ["2.50000000001e0", "2.5e0", "2.5e-30", "2.5e30"],
+                ),
+                (
+                    "1e-30",
+                    "0.0000000000045",
+                    Rounding::Up,
+                    [
+                        "4.50000000001e-12",
+                        "-4.5e-12",
+                        "4.5e-42",
+                        "2.22222222223e-19",
+                    ],
+                ),
+                (
+                    "-1.000000000000000000005",
+                    "7e100",
+                    Rounding::Up,
+                    [
+                        "7e100",
+                        "-7.00000000001e100",
+                        "-7.00000000001e100",
+                        "-1.42857142858e-101",
+                    ],
+                ),
+                (
+                    "123456789.987654321",
+                    "0.1",
+                    Rounding::Up,
+                    [
+                        "1.23456790088e8",
+                        "1.23456789888e8",
+                        "1.23456789988e7",
+                        "1.23456789988e9",
+                    ],
+                ),
+                (
+                    "-12345678901.5",
+                    "-12345678901.5",
+                    Rounding::Up,
+                    ["-2.4691357803e10", "0e0", "1.52415787539e20", "1e0"],
+                ),
+                (
+                    "-1.000000000000000000005",
+                    "1",
+                    Rounding::HalfDown,
+                    ["-5e-21", "-2e0", "-1e0", "-1e0"],
+                ),
+                (
+                    "-0.125",
+                    "-7",
+                    Rounding::HalfDown,
+                    ["-7.125e0", "6.875e0", "8.75e-1", "1.78571428571e-2"],
+                ),
+                (
+                    "1",
+                    "2.5",
+                    Rounding::HalfDown,
+                    ["3.5e0", "-1.5e0", "2.5e0", "4e-1"],
+                ),
+                (
+                    "1",
+                    "0.1",
+                    Rounding::HalfDown,
+                    ["1.1e0", "9e-1", "1e-1", "1e1"],
+                ),
+                (
+                    "0.0000000000045",
+                    "0.1",
+                    Rounding::HalfDown,
+                    [
+                        "1.00000000004e-1",
+                        "-9.99999999955e-2",
+                        "4.5e-13",
+                        "4.5e-11",
+                    ],
+                ),
+                (
+                    "7e100",
+                    "-4.4e25",
+                    Rounding::HalfDown,
+                    ["7e100", "7e100", "-3.08e126", "-1.59090909091e75"],
+                ),
+                (
+                    "-4.4e25",
+                    "-0.125",
+                    Rounding::HalfDown,
+                    ["-4.4e25", "-4.4e25", "5.5e24", "3.52e26"],
+                ),
+                (
+                    "123456789.987654321",
+                    "0.1",
+                    Rounding::HalfDown,
+                    [
+                        "1.23456790088e8",
+                        "1.23456789888e8",
+                        "1.23456789988e7",
+                        "1.23456789988e9",
+                    ],
+                ),
+            ] {
+                let ctx = FloatContext::new(12, rounding).unwrap();
+                let (a_f, b_f) = (float(a), float(b));
+
+                let res = [
+                    add_float(&a_f, &b_f, &ctx).unwrap(),
+                    sub_float(&a_f, &b_f, &ctx).unwrap(),
+                    mul_float(&a_f, &b_f, &ctx).unwrap(),
+                    div_float(&a_f, &b_f, &ctx).unwrap(),
+                ];
+
+                for (res, proof) in res.iter().zip(proof) {
+                    assert_eq!(proof, res.to_number(), "{a} {b} {rounding:?}");
+                }
+            }
+        }
+
+        #[test]
+        fn sqrt_test() {
+            let ctx = FloatContext::new(12, Rounding::HalfEven).unwrap();
+            for (num, proof) in [
+                ("1", "1e0"),
+                ("3", "1.73205080757e0"),
+                ("-7", "2.64575131106e0"),
+                ("2.5", "1.58113883008e0"),
+                ("-0.125", "3.53553390593e-1"),
+                ("123456789.987654321", "1.1111111105e4"),
+                ("-9.99999999999999999999", "3.16227766017e0"),
+                ("1e-30", "1e-15"),
+                ("-4.4e25", "6.63324958071e12"),
+                ("0.1", "3.16227766017e-1"),
+                ("7e100", "2.64575131106e50"),
+                ("-1.000000000000000000005", "1e0"),
+                ("0.0000000000045", "2.12132034356e-6"),
+                ("-12345678901.5", "1.11111110612e5"),
+            ] {
+                let res = sqrt_float(&float(num).abs(), &ctx).unwrap();
+                assert_eq!(proof, res.to_number(), "{num}");
+            }
+        }
+
+        #[test]
+        fn sqrt_directed_test() {
+            let num = float("1.000000000000000000005");
+            for (rounding, proof) in [
+                (Rounding::Up, "1.00000000001e0"),
+                (Rounding::Ceiling, "1.00000000001e0"),
+                (Rounding::Down, "1e0"),
+                (Rounding::HalfUp, "1e0"),
+            ] {
+                let ctx = FloatContext::new(12, rounding).unwrap();
+                assert_eq!(proof, sqrt_float(&num, &ctx).unwrap().to_number());
+            }
+
+            // exact root is not rounded
+            let ctx = FloatContext::new(12, Rounding::Up).unwrap();
+            assert_eq!(float("0.25"), sqrt_float(&float("0.0625"), &ctx).unwrap());
+        }
+
+        #[test]
+        fn sqrt_advanced_test() {
+            let ctx = FloatContext::new(40, Rounding::HalfEven).unwrap();
+            let proof = float("1.414213562373095048801688724209698078570");
+            assert_eq!(proof, sqrt_float(&float("2"), &ctx).unwrap());
+        }
+
+        #[test]
+        fn sqrt_negative_test() {
+            let ctx = FloatContext::new(12, Rounding::HalfEven).unwrap();
+            assert_eq!(None, sqrt_float(&float("-2"), &ctx));
+            assert_eq!(Some(float("0")), sqrt_float(&float("0"), &ctx));
+        }
+
+        #[test]
+        fn div_nought_test() {
+            let ctx = FloatContext::new(12, Rounding::HalfEven).unwrap();
+            assert_eq!(None, div_float(&float("1"), &float("0"), &ctx));
+        }
+
+        #[test]
+        fn huge_exponent_gap_test() {
+            let ctx = FloatContext::new(5, Rounding::Floor).unwrap();
+            let big = float("1e1000000000");
+            let tiny = float("1e-1000000000");
+
+            assert_eq!(Some(float("1e1000000000")), add_float(&big, &tiny, &ctx));
+            assert_eq!(
+                Some(float("9.9999e999999999")),
+                sub_float(&big, &tiny, &ctx)
+            );
+        }
+
+        #[test]
+        fn exp_overflow_test() {
+            let ctx = FloatContext::new(12, Rounding::HalfEven).unwrap();
+            let max = float("1e9223372036854775807");
+            let min = float("1e-9223372036854775808");
+
+            assert_eq!(None, mul_float(&max, &max, &ctx));
+            assert_eq!(None, mul_float(&min, &min, &ctx));
+            assert_eq!(None, div_float(&max, &min, &ctx));
+            assert_eq!(None, div_float(&min, &max, &ctx));
+            assert_eq!(None, add_float(&float("9e9223372036854775807"), &max, &ctx));
+            assert_eq!(
+                None,
+                sub_float(&float("-9e9223372036854775807"), &max, &ctx)
+            );
+
+            assert_eq!(Some(float("1e-1")), mul_float(&max, &min, &ctx));
+            assert_eq!(Some(max.clone()), add_float(&max, &min, &ctx));
+            assert_eq!(
+                Some(float("1e-4611686018427387904")),
+                sqrt_float(&min, &ctx)
+            );
+            assert_eq!(
+                Rel::Greater(None),
+                rel_float(&float("15e9223372036854775807"), &max)
+            );
+        }
+
+        #[test]
+        fn rel_test() {
+            for (a, b, proof) in [
+                ("1.5", "1.50", Rel::Equal),
+                ("1.5", "1.49", Rel::Greater(None)),
+                ("-1.5", "-1.49", Rel::Lesser(None)),
+                ("1e10", "9e9", Rel::Greater(None)),
+                ("-1e10", "9e9", Rel::Lesser(None)),
+                ("0", "-1e-100", Rel::Greater(None)),
+                ("0", "1e-100", Rel::Lesser(None)),
+                ("0", "0", Rel::Equal),
+            ] {
+                assert_eq!(proof, rel_float(&float(a), &float(b)), "{a} {b}");
+            }
+        }
+    }
+
+    mod gaussianint {
+        use crate::{GInt, Row, SRow};
+        use alloc::string::ToString;
+
+        #[test]
+        fn new_test() {
+            let z = GInt::new(SRow::new_from_num(3), SRow::new_from_num(-4));
+            assert_eq!(GInt::new_from_num(3, -4), z);
+            assert_eq!(&SRow::new_from_num(3), z.re());
+            assert_eq!(&SRow::new_from_num(-4), z.im());
+        }
+
+        #[test]
+        fn to_number_test() {
+            assert_eq!("3-4i", GInt::new_from_num(3, -4).to_number());
+            assert_eq!("-3+4i", GInt::new_from_num(-3, 4).to_number());
+            assert_eq!("0+0i", GInt::new_from_num(0, 0).to_string());
+        }
+
+        #[test]
+        fn norm_test() {
+            assert_eq!(Row::new_from_num(25), GInt::new_from_num(3, -4).norm());
+            assert_eq!(Row::nought(), GInt::new_from_num(0, 0).norm());
+        }
+
+        #[test]
+        fn unary_test() {
+            assert_eq!(GInt::new_from_num(3, 4), GInt::new_from_num(3, -4).conj());
+            assert_eq!(GInt::new_from_num(-3, 4), GInt::new_from_num(3, -4).neg());
+            assert!(GInt::new_from_num(0, 0).is_nought());
+        }
+
+        #[test]
+        fn is_unit_test() {
+            for (re, im) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                assert!(GInt::new_from_num(re, im).is_unit());
+            }
+
+            assert!(!GInt::new_from_num(1, 1).is_unit());
+            assert!(!GInt::new_from_num(0, 0).is_unit());
+        }
+
+        #[test]
+        fn normalize_test() {
+            for z in [(3, 4), (-4, 3), (-3, -4), (4, -3)] {
+                let z = GInt::new_from_num(z.0, z.1);
+                assert_eq!(GInt::new_from_num(3, 4), z.normalize());
+            }
+
+            assert_eq!(
+                GInt::new_from_num(5, 0),
+                GInt::new_from_num(0, -5).normalize()
+            );
+            assert_eq!(
+                GInt::new_from_num(0, 0),
+                GInt::new_from_num(0, 0).normalize()
+            );
+        }
+    }
+
+    mod gaussian_arithmetic {
+        use crate::{
+            add_gaussian, divrem_gaussian, gcd_gaussian, mul_gaussian, rel, sub_gaussian, GInt, Rel,
+        };
+
+        const NUMS: [(i128, i128); 8] = [
+            (0, 0),
+            (1, 0),
+            (0, -1),
+            (3, -4),
+            (-7, 2),
+            (12, 5),
+            (-100, -33),
+            (999, 1),
+        ];
+
+        #[test]
+        fn add_sub_mul_test() {
+            for (a, b) in NUMS {
+                for (c, d) in NUMS {
+                    let (x, y) = (GInt::new_from_num(a, b), GInt::new_from_num(c, d));
+
+                    assert_eq!(GInt::new_from_num(a + c, b + d), add_gaussian(&x, &y));
+                    assert_eq!(GInt::new_from_num(a - c, b - d), sub_gaussian(&x, &y));
+
+                    let proof = GInt::new_from_num(a * c - b * d, a * d + b * c);
+                    assert_eq!(proof, mul_gaussian(&x, &y));
+                }
+            }
+        }
+
+        #[test]
+        fn divrem_test() {
+            for (a, b) in NUMS {
+                for (c, d) in NUMS.into_iter().filter(|&z| z != (0, 0)) {
+                    let (x, y) = (GInt::new_from_num(a, b), GInt::new_from_num(c, d));
+                    let (ratio, rem) = divrem_gaussian(&x, &y).unwrap();
+
+                    assert_eq!(x, add_gaussian(&mul_gaussian(&ratio, &y), &rem));
+
+                    // 2 ⋅N(r) ≤ N(y)
+                    let rem_norm = rem.norm();
+                    let rem_norm = crate::add(&rem_norm, &rem_norm);
+                    assert!(!matches!(rel(&rem_norm, &y.norm()), Rel::Greater(_)));
+                }
+            }
+        }
+
+        #[test]
+        fn divrem_exact_test() {
+            // (2 +i)(1 +2i) = 5i
+            let res = divrem_gaussian(&GInt::new_from_num(0, 5), &GInt::new_from_num(2, 1));
+            assert_eq!(
+                Some((GInt::new_from_num(1, 2), GInt::new_from_num(0, 0))),
+                res
+            );
+        }
+
+        #[test]
+        fn divrem_nought_test() {
+            let res = divrem_gaussian(&GInt::new_from_num(1, 1), &GInt::new_from_num(0, 0));
+            assert_eq!(None, res);
+        }
+
+        #[test]
+        fn gcd_test() {
+            // 5 = (2 +i)(2 -i), 13 = (3 +2i)(3 -2i)
+            let x = mul_gaussian(&GInt::new_from_num(2, 1), &GInt::new_from_num(3, 2));
+            let y = mul_gaussian(&GInt::new_from_num(2, 1), &GInt::new_from_num(3, -2));
+            assert_eq!(GInt::new_from_num(2, 1), gcd_gaussian(&x, &y));
+
+            let x = GInt::new_from_num(5, 0);
+            let y = GInt::new_from_num(13, 0);
+            assert!(gcd_gaussian(&x, &y).is_unit());
+
+            let zero = GInt::new_from_num(0, 0);
+            assert_eq!(
+                GInt::new_from_num(3, 4),
+                gcd_gaussian(&zero, &GInt::new_from_num(-4, 3))
+            );
+            assert_eq!(zero, gcd_gaussian(&zero, &zero));
+        }
+    }
+
+    mod factorize_gaussian {
+        use crate::{factorize_gaussian, is_bpsw_prime, mul_gaussian, GInt};
+        use alloc::vec;
+
+        fn check(z: &GInt) {
+            let (unit, factors) = factorize_gaussian(z).unwrap();
+            assert!(unit.is_unit(), "{z}");
+
+            let mut product = unit;
+            for (pi, e) in &factors {
+                assert_eq!(&pi.normalize(), pi, "{z}");
+
+                // N(π) is prime or square of prime p ≡ 3 (mod 4)
+                let norm = pi.norm();
+                let prime =
+                    is_bpsw_prime(&norm) || (pi.im().is_nought() && is_bpsw_prime(pi.re().mag()));
+                assert!(prime, "{z} {pi}");
+
+                for _ in 0..*e {
+                    product = mul_gaussian(&product, pi);
+                }
+            }
+
+            assert_eq!(z, &product);
+        }
+
+        #[test]
+        fn basic_test() {
+            let (unit, factors) = factorize_gaussian(&GInt::new_from_num(5, 0)).unwrap();
+            assert_eq!(GInt::new_from_num(0, -1), unit);
+            assert_eq!(
+                vec![(GInt::new_from_num(1, 2), 1), (GInt::new_from_num(2, 1), 1)],
+                factors
+            );
+
+            let (unit, factors) = factorize_gaussian(&GInt::new_from_num(2, 0)).unwrap();
+            assert_eq!(GInt::new_from_num(0, -1), unit);
+            assert_eq!(vec![(GInt::new_from_num(1, 1), 2)], factors);
+        }
+
+        #[test]
+        fn universal_test() {
+            for re in -20..=20 {
+                for im in -20..=20 {
+                    if (re, im) != (0, 0) {
+                        check(&GInt::new_from_num(re, im));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn unit_test() {
+            let (unit, factors) = factorize_gaussian(&GInt::new_from_num(0, -1)).unwrap();
+            assert_eq!(GInt::new_from_num(0, -1), unit);
+            assert!(factors.is_empty());
+        }
+
+        #[test]
+        fn advanced_test() {
+            // (12345 +6789i)² ⋅3 ⋅(1 +i) ⋅1000003
+            let z = GInt::new_from_num(12345, 6789);
+            let z = mul_gaussian(&z, &z);
+            let z = mul_gaussian(&z, &GInt::new_from_num(3, 3));
+            let z = mul_gaussian(&z, &GInt::new_from_num(1_000_003, 0));
+
+            check(&z);
+        }
+
+        #[test]
+        fn nought_test() {
+            assert_eq!(None, factorize_gaussian(&GInt::new_from_num(0, 0)));
+        }
+    }
+
+    mod modpow {
+        use crate::{modpow, Row};
+
+        #[test]
+        fn basic_test() {
+            let base = Row::new_from_num(4);
+            let exp = Row::new_from_num(13);
+            let modulus = Row::new_from_num(497);
+
+            assert_eq!(Some(Row::new_from_num(445)), modpow(&base, &exp, &modulus));
+        }
+
+        #[test]
+        fn universal_test() {
+            for (base, exp, modulus) in [
+                (2u128, 10u128, 1000u128),
+                (3, 200, 1_000_000_007),
+                (123_456_789, 987_654_321, 1_000_000_009),
+                (10, 99, 7),
+                (7, 1, 5),
+                (0, 5, 3),
+            ] {
+                let mut proof = 1;
+                let mut b = base % modulus;
+                let mut e = exp;
+                while e > 0 {
+                    if e % 2 == 1 {
+                        proof = proof * b % modulus;
+                    }
+                    b = b * b % modulus;
+                    e /= 2;
+                }
+
+                let res = modpow(
+                    &Row::new_from_num(base),
+                    &Row::new_from_num(exp),
+                    &Row::new_from_num(modulus),
+                );
+
+                assert_eq!(
+                    Some(Row::new_from_num(proof)),
+                    res,
+                    "{base}^{exp} mod {modulus}"
+                );
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime, Fermat: 3ᵖ⁻¹ ≡ 1
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            let exp = Row::new_from_str("170141183460469231731687303715884105726").unwrap();
+
+            assert_eq!(Some(Row::unity()), modpow(&Row::new_from_num(3), &exp, &p));
+        }
+
+        #[test]
+        fn nought_modulus_test() {
+            let unity = Row::unity();
+            assert_eq!(None, modpow(&unity, &unity, &Row::nought()));
+        }
+    }
+
+    mod pow_places {
+        use crate::{mul, mulmod, pow, pow_places, Row};
+
+        #[test]
+        fn basic_test() {
+            let base = Row::new_from_num(3);
+            for exp in [0, 1, 9, 10, 25, 107] {
+                let res = pow_places(&base, &Row::new_from_num(exp), Row::unity(), mul);
+                assert_eq!(pow(&base, exp as u16), res, "{exp}");
+            }
+        }
+
+        #[test]
+        fn modular_test() {
+            let modulus = Row::new_from_num(1_000_000_007);
+            let exp = Row::new_from_num(1_000_000_006);
+
+            // Fermat: aᵖ⁻¹ ≡ 1
+            let res = pow_places(&Row::new_from_num(5), &exp, Row::unity(), |a, b| {
+                mulmod(a, b, &modulus)
+            });
+            assert_eq!(Row::unity(), res);
+        }
+    }
+
+    mod modpow_shortcut {
+        use crate::{modpow_shortcut, Row};
+
+        #[test]
+        fn nought_modulus_test() {
+            let res = modpow_shortcut(&Row::unity(), &Row::nought());
+            assert_eq!(Some(None), res);
+        }
+
+        #[test]
+        fn unity_modulus_test() {
+            let res = modpow_shortcut(&Row::nought(), &Row::unity());
+            assert_eq!(Some(Some(Row::nought())), res);
+        }
+
+        #[test]
+        fn nought_exp_test() {
+            let res = modpow_shortcut(&Row::nought(), &Row::new_from_num(10));
+            assert_eq!(Some(Some(Row::unity())), res);
+        }
+
+        #[test]
+        fn none_test() {
+            let res = modpow_shortcut(&Row::unity(), &Row::new_from_num(10));
+            assert_eq!(None, res);
+        }
+    }
+
+    mod mod_context {
+        use crate::{rem, ModContext, Row};
+
+        #[test]
+        fn basic_test() {
+            let ctx = ModContext::new(&Row::new_from_num(97)).unwrap();
+            assert_eq!(&Row::new_from_num(97), ctx.modulus());
+            assert_eq!(Row::new_from_num(30), ctx.reduce(&Row::new_from_num(9_536)));
+        }
+
+        #[test]
+        fn nought_modulus_test() {
+            assert_eq!(None, ModContext::new(&Row::nought()));
+        }
+
+        #[test]
+        fn unity_modulus_test() {
+            let ctx = ModContext::new(&Row::unity()).unwrap();
+            for n in [0, 1, 9, 10, 99_999] {
+                assert_eq!(Row::nought(), ctx.reduce(&Row::new_from_num(n)));
+            }
+        }
+
+        #[test]
+        fn reduce_test() {
+            let moduli = [
+                "7",
+                "10",
+                "999",
+                "1000",
+                "1000000007",
+                "170141183460469231731687303715884105727",
+                "99999999999999999999999999999999999999999",
+            ];
+
+            for m in moduli {
+                let m = Row::new_from_str(m).unwrap();
+                let ctx = ModContext::new(&m).unwrap();
+
+                let mut num = Row::new_from_num(3);
+                while num.len() < 3 * m.len() {
+                    assert_eq!(rem(&num, &m), ctx.reduce(&num), "{num:?} mod {m:?}");
+                    num = crate::mul(&num, &Row::new_from_num(7_919));
+                }
+
+                let max = crate::sub(&crate::mul(&m, &m), &Row::unity()).unwrap();
+                assert_eq!(rem(&max, &m), ctx.reduce(&max));
+            }
+        }
+    }
+
+    mod mod_int {
+        use crate::{ModContext, Row};
+
+        #[test]
+        fn basic_test() {
+            let ctx = ModContext::new(&Row::new_from_num(13)).unwrap();
+            let a = ctx.int(&Row::new_from_num(40));
+            let b = ctx.int(&Row::new_from_num(9));
+
+            assert_eq!(&Row::new_from_num(1), a.value());
+            assert_eq!(&Row::new_from_num(10), a.add(&b).unwrap().value());
+            assert_eq!(&Row::new_from_num(5), a.sub(&b).unwrap().value());
+            assert_eq!(&Row::new_from_num(9), a.mul(&b).unwrap().value());
+            assert_eq!(&Row::new_from_num(4), b.neg().value());
+            assert_eq!(&Row::new_from_num(3), b.inv().unwrap().value());
+            assert_eq!(&Row::new_from_num(3), b.pow(&Row::new_from_num(5)).value());
+        }
+
+        #[test]
+        fn universal_test() {
+            for m in [1u128, 2, 10, 97, 1_000, 65_537, 1_000_000_007] {
+                let ctx = ModContext::new(&Row::new_from_num(m)).unwrap();
+
+                for (a, b) in [
+                    (0u128, 0u128),
+                    (1, m - 1),
+                    (12_345, 678_910),
+                    (m + 3, 5 * m),
+                ] {
+                    let x = ctx.int(&Row::new_from_num(a));
+                    let y = ctx.int(&Row::new_from_num(b));
+                    let (a, b) = (a % m, b % m);
+
+                    assert_eq!(&Row::new_from_num((a + b) % m), x.add(&y).unwrap().value());
+                    assert_eq!(
+                        &Row::new_from_num((a + m - b) % m),
+                        x.sub(&y).unwrap().value()
+                    );
+                    assert_eq!(&Row::new_from_num(a * b % m), x.mul(&y).unwrap().value());
+                    assert_eq!(&Row::new_from_num((m - a) % m), x.neg().value());
+
+                    let mut proof = 1 % m;
+                    let (mut base, mut exp) = (a, b);
+                    while exp > 0 {
+                        if exp % 2 == 1 {
+                            proof = proof * base % m;
+                        }
+                        base = base * base % m;
+                        exp /= 2;
+                    }
+
+                    let pow = x.pow(&Row::new_from_num(b));
+                    assert_eq!(&Row::new_from_num(proof), pow.value(), "{a}^{b} mod {m}");
+
+                    match x.inv() {
+                        Some(inv) => assert_eq!(
+                            1 % m,
+                            inv.value().to_number().parse::<u128>().unwrap() * a % m
+                        ),
+                        None => assert!(m > 1 && a != 1),
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime, Fermat: 3ᵖ⁻¹ ≡ 1
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            let exp = Row::new_from_str("170141183460469231731687303715884105726").unwrap();
+
+            let ctx = ModContext::new(&p).unwrap();
+            let three = ctx.int(&Row::new_from_num(3));
+            assert_eq!(&Row::unity(), three.pow(&exp).value());
+
+            let inv = three.inv().unwrap();
+            assert_eq!(&Row::unity(), three.mul(&inv).unwrap().value());
+        }
+
+        #[test]
+        fn same_modulus_test() {
+            let ctx1 = ModContext::new(&Row::new_from_num(11)).unwrap();
+            let ctx2 = ModContext::new(&Row::new_from_num(11)).unwrap();
+
+            let a = ctx1.int(&Row::new_from_num(7));
+            let b = ctx2.int(&Row::new_from_num(8));
+            assert_eq!(&Row::new_from_num(4), a.add(&b).unwrap().value());
+        }
+
+        #[test]
+        fn other_modulus_test() {
+            let ctx1 = ModContext::new(&Row::new_from_num(11)).unwrap();
+            let ctx2 = ModContext::new(&Row::new_from_num(13)).unwrap();
+
+            let a = ctx1.int(&Row::unity());
+            let b = ctx2.int(&Row::unity());
+            assert_eq!(None, a.add(&b));
+            assert_eq!(None, a.sub(&b));
+            assert_eq!(None, a.mul(&b));
+        }
+    }
+
+    mod fixed_decimal {
+        use crate::{rel_fixed, FixedDecimal, Rel, Row};
+
+        type Fd3 = FixedDecimal<3>;
+
+        #[test]
+        fn ctor_test() {
+            assert_eq!(&[5, 4, 3], Fd3::new_from_num(12_345).places());
+            assert_eq!(
+                &[5, 4, 3],
+                Fd3::new_from_row(&Row::new_from_num(12_345)).places()
+            );
+            assert_eq!(Ok(Fd3::new_from_num(345)), Fd3::new_from_str("12345"));
+            assert_eq!(Ok(Fd3::new_from_num(7)), Fd3::new_from_str("007"));
+            assert_eq!(Err(Some(1)), Fd3::new_from_str("1a345"));
+            assert_eq!(Err(None), Fd3::new_from_str(""));
+        }
+
+        #[test]
+        fn to_number_test() {
+            let num = Fd3::new_from_num(42);
+            assert_eq!("42", num.to_number());
+            assert_eq!("042", num.to_places());
+            assert_eq!("42", alloc::format!("{num}"));
+            assert_eq!(Row::new_from_num(42), num.to_row());
+
+            assert_eq!("0", Fd3::nought().to_number());
+            assert_eq!(Row::nought(), Fd3::nought().to_row());
+            assert_eq!("999", Fd3::max().to_number());
+        }
+
+        #[test]
+        fn odometer_test() {
+            let unity = Fd3::unity();
+            let (num, overflow) = Fd3::max().overflowing_add(&unity);
+            assert_eq!(Fd3::nought(), num);
+            assert!(overflow);
+
+            let (num, overflow) = Fd3::nought().overflowing_sub(&unity);
+            assert_eq!(Fd3::max(), num);
+            assert!(overflow);
+        }
+
+        #[test]
+        fn universal_test() {
+            let m = 1_000u128;
+            let nums = [0, 1, 2, 9, 10, 31, 99, 100, 500, 501, 999];
+
+            for a in nums {
+                let x = Fd3::new_from_num(a);
+                for b in nums {
+                    let y = Fd3::new_from_num(b);
+
+                    let sum = a + b;
+                    assert_eq!((Fd3::new_from_num(sum), sum >= m), x.overflowing_add(&y));
+                    assert_eq!(Fd3::new_from_num(sum.min(m - 1)), x.saturating_add(&y));
+
+                    let diff = (a + m - b) % m;
+                    assert_eq!((Fd3::new_from_num(diff), a < b), x.overflowing_sub(&y));
+                    assert_eq!(Fd3::new_from_num(a.saturating_sub(b)), x.saturating_sub(&y));
+
+                    let prod = a * b;
+                    assert_eq!(
+                        (Fd3::new_from_num(prod), prod >= m),
+                        x.overflowing_mul(&y),
+                        "{a} ⋅{b}"
+                    );
+                    assert_eq!(Fd3::new_from_num(prod.min(m - 1)), x.saturating_mul(&y));
+
+                    let divrem = a
+                        .checked_div(b)
+                        .map(|r| (Fd3::new_from_num(r), Fd3::new_from_num(a % b)));
+                    assert_eq!(divrem, x.checked_divrem(&y), "{a} ÷{b}");
+
+                    let rel = match a.cmp(&b) {
+                        core::cmp::Ordering::Less => Rel::Lesser(None),
+                        core::cmp::Ordering::Equal => Rel::Equal,
+                        core::cmp::Ordering::Greater => Rel::Greater(None),
+                    };
+                    assert_eq!(rel, rel_fixed(&x, &y));
+                }
+
+                for exp in [0u32, 1, 2, 3, 7, 10, 100] {
+                    let pow = a.checked_pow(exp);
+                    let proof = match pow {
+                        Some(p) if p < m => (Fd3::new_from_num(p), false),
+                        _ => {
+                            let mut p = 1;
+                            for _ in 0..exp {
+                                p = p * a % m;
+                            }
+                            (Fd3::new_from_num(p), true)
+                        }
+                    };
+
+                    assert_eq!(proof, x.overflowing_pow(exp), "{a}^{exp}");
+                }
+
+                let neg = (Fd3::new_from_num((m - a) % m), a != 0);
+                assert_eq!(neg, x.overflowing_neg());
+            }
+        }
+
+        #[test]
+        fn checked_test() {
+            let num = Fd3::new_from_num(400);
+            let two = Fd3::new_from_num(2);
+            let three = Fd3::new_from_num(3);
+
+            assert_eq!(Some(Fd3::new_from_num(800)), num.checked_mul(&two));
+            assert_eq!(None, num.checked_mul(&three));
+            assert_eq!(Fd3::new_from_num(200), num.wrapping_mul(&three));
+            assert_eq!(None, num.checked_add(&Fd3::new_from_num(600)));
+            assert_eq!(None, two.checked_sub(&three));
+            assert_eq!(Some(Fd3::nought()), Fd3::nought().checked_neg());
+            assert_eq!(None, two.checked_neg());
+            assert_eq!(Fd3::new_from_num(998), two.wrapping_neg());
+            assert_eq!(Some(Fd3::new_from_num(133)), num.checked_div(&three));
+            assert_eq!(Some(Fd3::new_from_num(1)), num.checked_rem(&three));
+            assert_eq!(None, num.checked_rem(&Fd3::nought()));
+            assert_eq!(Some(Fd3::new_from_num(729)), three.checked_pow(6));
+            assert_eq!(None, three.checked_pow(7));
+            assert_eq!(Fd3::new_from_num(187), three.wrapping_pow(7));
+            assert_eq!(Fd3::max(), three.saturating_pow(7));
+        }
+
+        #[test]
+        fn pow_overflow_test() {
+            // 10² fits, 10³ does not
+            let ten = Fd3::new_from_num(10);
+            assert_eq!((Fd3::new_from_num(100), false), ten.overflowing_pow(2));
+            assert_eq!((Fd3::nought(), true), ten.overflowing_pow(3));
+
+            // 31² = 961, 31³ = 29791
+            let num = Fd3::new_from_num(31);
+            assert_eq!((Fd3::new_from_num(961), false), num.overflowing_pow(2));
+            assert!(num.overflowing_pow(3).1);
+        }
+
+        #[test]
+        fn advanced_test() {
+            type Fd40 = FixedDecimal<40>;
+
+            let num = Fd40::new_from_str("9999999999999999999999999999999999999999").unwrap();
+            let (sum, overflow) = num.overflowing_add(&Fd40::unity());
+            assert_eq!(Fd40::nought(), sum);
+            assert!(overflow);
+
+            // (10⁴⁰ -1)² = 10⁸⁰ -2 ⋅10⁴⁰ +1 ≡ 1
+            let (prod, overflow) = num.overflowing_mul(&num);
+            assert_eq!(Fd40::unity(), prod);
+            assert!(overflow);
+
+            let num = Fd40::new_from_num(u128::MAX);
+            let ratio = num.checked_div(&Fd40::new_from_num(u64::MAX as u128));
+            assert_eq!(Some(Fd40::new_from_num(u64::MAX as u128 + 2)), ratio);
+
+            // 2¹³² = 5444517870735015415413993718908291383296
+            let pow = Fd40::new_from_num(2).overflowing_pow(132);
+            assert_eq!(
+                "5444517870735015415413993718908291383296",
+                pow.0.to_number()
+            );
+            assert!(!pow.1);
+            assert!(Fd40::new_from_num(2).overflowing_pow(133).1);
+        }
+
+        #[test]
+        fn zero_width_test() {
+            type Fd0 = FixedDecimal<0>;
+
+            assert_eq!(Fd0::nought(), Fd0::unity());
+            assert_eq!("0", Fd0::new_from_num(5).to_number());
+            assert_eq!("", Fd0::nought().to_places());
+            assert_eq!((Fd0::nought(), true), Fd0::nought().overflowing_pow(0));
+            assert_eq!(None, Fd0::nought().checked_div(&Fd0::nought()));
+        }
+    }
+
+    mod is_probable_prime {
+        use crate::{is_probable_prime, Row};
+
+        #[test]
+        fn small_numbers_test() {
+            for n in 0..10_100u128 {
+                let proof = n > 1 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+
+                assert_eq!(proof, is_probable_prime(&Row::new_from_num(n), 0), "{n}");
+            }
+        }
+
+        #[test]
+        fn strong_pseudoprimes_test() {
+            // strong pseudoprimes to bases 2, 3, 5, 7 and to first 12 primes
+            for n in [3_215_031_751u128, 318_665_857_834_031_151_167_461] {
+                assert!(!is_probable_prime(&Row::new_from_num(n), 0), "{n}");
+            }
+        }
+
+        #[test]
+        fn deterministic_test() {
+            // 2⁶¹ -1, 2⁸⁹ -1 are primes
+            for n in [
+                2_305_843_009_213_693_951u128,
+                618_970_019_642_690_137_449_562_111,
+            ] {
+                assert!(is_probable_prime(&Row::new_from_num(n), 0), "{n}");
+            }
+        }
+
+        #[test]
+        fn probabilistic_test() {
+            let prime = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert!(is_probable_prime(&prime, 5));
+
+            // (2⁶¹ -1)(2⁸⁹ -1)
+            let composite =
+                Row::new_from_str("1427247692705959880439315947500961989719490561").unwrap();
+            assert!(!is_probable_prime(&composite, 5));
+        }
+
+        #[test]
+        fn nought_rounds_test() {
+            // (2⁶¹ -1)(2⁸⁹ -1), above deterministic bound
+            let composite =
+                Row::new_from_str("1427247692705959880439315947500961989719490561").unwrap();
+            assert!(!is_probable_prime(&composite, 0));
+        }
+    }
+
+    mod is_bpsw_prime {
+        use crate::{is_bpsw_prime, Row};
+
+        #[test]
+        fn basic_test() {
+            for (n, proof) in [
+                (0u128, false),
+                (1, false),
+                (2, true),
+                (97, true),
+                (561, false),
+                (10_007, true),
+                (3_215_031_751, false),
+                (2_305_843_009_213_693_951, true),
+            ] {
+                assert_eq!(proof, is_bpsw_prime(&Row::new_from_num(n)), "{n}");
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            let prime = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert!(is_bpsw_prime(&prime));
+
+            let composite =
+                Row::new_from_str("1427247692705959880439315947500961989719490561").unwrap();
+            assert!(!is_bpsw_prime(&composite));
+        }
+
+        #[test]
+        fn perfect_square_test() {
+            // 10007²
+            let square = Row::new_from_num(100_140_049);
+            assert!(!is_bpsw_prime(&square));
+        }
+    }
+
+    mod next_prime {
+        use crate::{next_prime, Row};
+
+        #[test]
+        fn basic_test() {
+            for (n, proof) in [
+                (0u128, 2u128),
+                (1, 2),
+                (2, 3),
+                (3, 5),
+                (24, 29),
+                (89, 97),
+                (99_991, 100_003),
+                (1_000_000_000, 1_000_000_007),
+            ] {
+                assert_eq!(
+                    Row::new_from_num(proof),
+                    next_prime(&Row::new_from_num(n)),
+                    "{n}"
+                );
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 10²⁰ +39
+            let num = Row::new_from_str("100000000000000000000").unwrap();
+            let proof = Row::new_from_str("100000000000000000039").unwrap();
+            assert_eq!(proof, next_prime(&num));
+        }
+
+        #[test]
+        fn gap_test() {
+            // maximal gap 1131 follows 1693182318746371
+            let num = Row::new_from_num(1_693_182_318_746_371);
+            let proof = Row::new_from_num(1_693_182_318_747_503);
+            assert_eq!(proof, next_prime(&num));
+        }
+    }
+
+    mod prev_prime {
+        use crate::{prev_prime, Row};
+
+        #[test]
+        fn basic_test() {
+            for (n, proof) in [
+                (3u128, 2u128),
+                (4, 3),
+                (29, 23),
+                (100, 97),
+                (100_003, 99_991),
+                (1_000_000_007, 999_999_937),
+            ] {
+                assert_eq!(
+                    Some(Row::new_from_num(proof)),
+                    prev_prime(&Row::new_from_num(n)),
+                    "{n}"
+                );
+            }
+        }
+
+        #[test]
+        fn none_test() {
+            for n in [0u128, 1, 2] {
+                assert_eq!(None, prev_prime(&Row::new_from_num(n)), "{n}");
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 10²⁰ −11
+            let num = Row::new_from_str("100000000000000000000").unwrap();
+            let proof = Row::new_from_str("99999999999999999989").unwrap();
+            assert_eq!(Some(proof), prev_prime(&num));
+        }
+    }
+
+    mod primes_in_range {
+        use crate::{primes_below, primes_in_range, Row};
+        use alloc::vec::Vec;
+
+        #[test]
+        fn basic_test() {
+            let primes = primes_in_range(&Row::new_from_num(10), &Row::new_from_num(30));
+            let proof = [11u128, 13, 17, 19, 23, 29].map(Row::new_from_num);
+            assert_eq!(proof.as_slice(), primes.collect::<Vec<Row>>());
+        }
+
+        #[test]
+        fn empty_test() {
+            let primes = primes_in_range(&Row::new_from_num(30), &Row::new_from_num(10));
+            assert_eq!(0, primes.count());
+
+            let primes = primes_in_range(&Row::new_from_num(24), &Row::new_from_num(29));
+            assert_eq!(0, primes.count());
+        }
+
+        #[test]
+        fn universal_test() {
+            let proof = primes_below(25_000)
+                .into_iter()
+                .map(|p| Row::new_from_num(p as u128))
+                .collect::<Vec<Row>>();
+
+            let primes = primes_in_range(&Row::nought(), &Row::new_from_num(25_000));
+            assert_eq!(proof, primes.collect::<Vec<Row>>());
+        }
+
+        #[test]
+        fn advanced_test() {
+            // primes between 10¹⁸ and 10¹⁸ +100
+            let lo = Row::new_from_str("1000000000000000000").unwrap();
+            let hi = Row::new_from_str("1000000000000000100").unwrap();
+
+            let proof =
+                [3u128, 9, 31, 79].map(|o| Row::new_from_num(1_000_000_000_000_000_000 + o));
+            assert_eq!(
+                proof.as_slice(),
+                primes_in_range(&lo, &hi).collect::<Vec<Row>>()
+            );
+        }
+    }
+
+    mod strong_lucas {
+        use crate::{strong_lucas, Row};
+
+        #[test]
+        fn primes_test() {
+            for p in [101u128, 103, 10_007, 1_000_000_007] {
+                assert!(strong_lucas(&Row::new_from_num(p)), "{p}");
+            }
+        }
+
+        #[test]
+        fn pseudoprime_test() {
+            // smallest strong Lucas pseudoprimes passes, Miller–Rabin catches them
+            for n in [5459u128, 5777, 10877] {
+                assert!(strong_lucas(&Row::new_from_num(n)), "{n}");
+            }
+        }
+
+        #[test]
+        fn composites_test() {
+            for n in [10_403u128, 1_000_000_007 * 101] {
+                assert!(!strong_lucas(&Row::new_from_num(n)), "{n}");
+            }
+        }
+    }
+
+    mod jacobi {
+        use crate::{jacobi, Row};
+
+        // (a/n) = Πᵢ (a/pᵢ)ᵉⁱ, (a/p) ≡ a⁽ᵖ⁻¹⁾ᐟ² (mod p)
+        fn proof(a: u128, mut n: u128) -> i8 {
+            let mut res = 1;
+            let mut p = 3;
+            while n > 1 {
+                while n.is_multiple_of(p) {
+                    n /= p;
+
+                    let mut euler = 1;
+                    for _ in 0..(p - 1) / 2 {
+                        euler = euler * (a % p) % p;
+                    }
+
+                    res *= match euler {
+                        0 => 0,
+                        1 => 1,
+                        _ => -1,
+                    };
+                }
+
+                p += 2;
+            }
+
+            res
+        }
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(
+                Some(1),
+                jacobi(&Row::new_from_num(1), &Row::new_from_num(3))
+            );
+            assert_eq!(
+                Some(-1),
+                jacobi(&Row::new_from_num(2), &Row::new_from_num(3))
+            );
+            assert_eq!(
+                Some(0),
+                jacobi(&Row::new_from_num(3), &Row::new_from_num(9))
+            );
+            assert_eq!(
+                Some(1),
+                jacobi(&Row::new_from_num(19), &Row::new_from_num(45))
+            );
+            assert_eq!(
+                Some(-1),
+                jacobi(&Row::new_from_num(1001), &Row::new_from_num(9907))
+            );
+        }
+
+        #[test]
+        fn universal_test() {
+            for n in (1..200u128).step_by(2) {
+                for a in 0..60u128 {
+                    let res = jacobi(&Row::new_from_num(a), &Row::new_from_num(n));
+                    assert_eq!(Some(proof(a, n)), res, "{a} {n}");
+                }
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            // 2¹²⁷ -1 ≡ 7 (mod 8), 2 is quadratic residue
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert_eq!(Some(1), jacobi(&Row::new_from_num(2), &p));
+            assert_eq!(
+                Some(-1),
+                jacobi(&crate::sub(&p, &Row::unity()).unwrap(), &p)
+            );
+        }
+
+        #[test]
+        fn even_n_test() {
+            assert_eq!(None, jacobi(&Row::new_from_num(3), &Row::new_from_num(10)));
+            assert_eq!(None, jacobi(&Row::new_from_num(3), &Row::nought()));
+        }
+    }
+
+    mod legendre {
+        use crate::{legendre, Row};
+
+        #[test]
+        fn basic_test() {
+            let p = Row::new_from_num(11);
+            for (a, proof) in [
+                (0, 0),
+                (1, 1),
+                (2, -1),
+                (3, 1),
+                (4, 1),
+                (5, 1),
+                (6, -1),
+                (22, 0),
+            ] {
+                assert_eq!(Some(proof), legendre(&Row::new_from_num(a), &p), "{a}");
+            }
+        }
+
+        #[test]
+        fn invalid_p_test() {
+            assert_eq!(None, legendre(&Row::new_from_num(3), &Row::unity()));
+            assert_eq!(None, legendre(&Row::new_from_num(3), &Row::new_from_num(2)));
+        }
+    }
+
+    mod kronecker {
+        use crate::{jacobi, kronecker, Row};
+
+        #[test]
+        fn nought_n_test() {
+            assert_eq!(1, kronecker(&Row::unity(), &Row::nought()));
+            assert_eq!(0, kronecker(&Row::new_from_num(2), &Row::nought()));
+        }
+
+        #[test]
+        fn two_test() {
+            for (a, proof) in [(0, 0), (1, 1), (3, -1), (5, -1), (7, 1), (9, 1), (10, 0)] {
+                assert_eq!(
+                    proof,
+                    kronecker(&Row::new_from_num(a), &Row::new_from_num(2)),
+                    "{a}"
+                );
+            }
+        }
+
+        #[test]
+        fn basic_test() {
+            // (5/12) = (5/2)² ⋅(5/3) = -1
+            assert_eq!(-1, kronecker(&Row::new_from_num(5), &Row::new_from_num(12)));
+            // (3/8) = (3/2)³ = -1
+            assert_eq!(-1, kronecker(&Row::new_from_num(3), &Row::new_from_num(8)));
+            // (7/1) = 1
+            assert_eq!(1, kronecker(&Row::new_from_num(7), &Row::unity()));
+        }
+
+        #[test]
+        fn odd_n_test() {
+            for n in (1..100u128).step_by(2) {
+                for a in 0..30u128 {
+                    let a = Row::new_from_num(a);
+                    let n = Row::new_from_num(n);
+                    assert_eq!(jacobi(&a, &n).unwrap(), kronecker(&a, &n));
+                }
+            }
+        }
+    }
+
+    mod odd_part {
+        use crate::{odd_part, Row};
+
+        #[test]
+        fn basic_test() {
+            let (odd, twos) = odd_part(&Row::new_from_num(96));
+            assert_eq!(Row::new_from_num(3), odd);
+            assert_eq!(5, twos);
+        }
+    }
+
+    mod bits {
+        use crate::{bits, Row};
+        use alloc::vec;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(
+                vec![false, true, false, true, true],
+                bits(&Row::new_from_num(26))
+            );
+        }
+    }
+
+    mod rem_num_raw {
+        use crate::{rem_num_raw, Row};
+
+        #[test]
+        fn basic_test() {
+            let row = Row::new_from_str("123456789012345678901234567890").unwrap();
+            assert_eq!(
+                123456789012345678901234567890 % 97,
+                rem_num_raw(&row.row, 97) as u128
+            );
+        }
+    }
+
+    mod to_num_raw {
+        use crate::{to_num_raw, Row};
+
+        #[test]
+        fn basic_test() {
+            let row = Row::new_from_num(u128::MAX);
+            assert_eq!(Some(u128::MAX), to_num_raw(&row.row));
+        }
+
+        #[test]
+        fn overflow_test() {
+            let row = Row::new_from_str("340282366920938463463374607431768211456").unwrap();
+            assert_eq!(None, to_num_raw(&row.row));
+        }
+    }
+
+    mod gcd {
+        use crate::{gcd, Row};
+
+        #[test]
+        fn universal_test() {
+            for (a, b, proof) in [
+                (0, 0, 0),
+                (0, 7, 7),
+                (7, 0, 7),
+                (12, 18, 6),
+                (17, 31, 1),
+                (1_000_000_000_000, 250, 250),
+            ] {
+                let res = gcd(&Row::new_from_num(a), &Row::new_from_num(b));
+                assert_eq!(Row::new_from_num(proof), res, "{a} {b}");
+            }
+        }
+    }
+
+    mod modinv {
+        use crate::{modinv, Row};
+
+        #[test]
+        fn basic_test() {
+            let res = modinv(&Row::new_from_num(3), &Row::new_from_num(11));
+            assert_eq!(Some(Row::new_from_num(4)), res);
+        }
+
+        #[test]
+        fn universal_test() {
+            let modulus = 1_000_000_007u128;
+            for num in [2u128, 10, 123_456_789, 1_000_000_006, 2_000_000_016] {
+                let inv = modinv(&Row::new_from_num(num), &Row::new_from_num(modulus)).unwrap();
+                let inv = inv.to_number().parse::<u128>().unwrap();
+
+                assert!(inv < modulus);
+                assert_eq!(1, num % modulus * inv % modulus, "{num}");
+            }
+        }
+
+        #[test]
+        fn not_coprime_test() {
+            let res = modinv(&Row::new_from_num(6), &Row::new_from_num(9));
+            assert_eq!(None, res);
+        }
+
+        #[test]
+        fn nought_modulus_test() {
+            let res = modinv(&Row::new_from_num(6), &Row::nought());
+            assert_eq!(None, res);
+        }
+
+        #[test]
+        fn unity_modulus_test() {
+            let res = modinv(&Row::new_from_num(6), &Row::unity());
+            assert_eq!(Some(Row::nought()), res);
+        }
+    }
+
+    mod modinv_or_gcd {
+        use crate::{modinv_or_gcd, Row};
+
+        #[test]
+        fn gcd_test() {
+            let res = modinv_or_gcd(&Row::new_from_num(21), &Row::new_from_num(35));
+            assert_eq!(Err(Row::new_from_num(7)), res);
+        }
+    }
+
+    mod crt {
+        use crate::{crt, Row};
+        use alloc::vec::Vec;
+
+        fn congruences(nums: &[(u128, u128)]) -> Vec<(Row, Row)> {
+            nums.iter()
+                .map(|c| (Row::new_from_num(c.0), Row::new_from_num(c.1)))
+                .collect()
+        }
+
+        fn proof(r: u128, m: u128) -> Option<(Row, Row)> {
+            Some((Row::new_from_num(r), Row::new_from_num(m)))
+        }
+
+        #[test]
+        fn basic_test() {
+            let res = crt(&congruences(&[(2, 3), (3, 5), (2, 7)]));
+            assert_eq!(proof(23, 105), res);
+        }
+
+        #[test]
+        fn non_coprime_test() {
+            let res = crt(&congruences(&[(2, 6), (8, 10)]));
+            assert_eq!(proof(8, 30), res);
+
+            let res = crt(&congruences(&[(3, 4), (3, 8), (7, 12)]));
+            assert_eq!(proof(19, 24), res);
+        }
+
+        #[test]
+        fn inconsistent_test() {
+            let res = crt(&congruences(&[(1, 6), (2, 4)]));
+            assert_eq!(None, res);
+        }
+
+        #[test]
+        fn unreduced_residue_test() {
+            let res = crt(&congruences(&[(17, 5), (100, 7)]));
+            assert_eq!(proof(2, 35), res);
+        }
+
+        #[test]
+        fn universal_test() {
+            let moduli = [7u128, 12, 18, 25, 35];
+            for x in [0u128, 1, 123, 4321, 9999, 43_199] {
+                let nums = moduli.iter().map(|&m| (x % m, m)).collect::<Vec<_>>();
+                let res = crt(&congruences(&nums));
+
+                // lcm = 2² ⋅3² ⋅5² ⋅7
+                assert_eq!(proof(x % 6300, 6300), res, "{x}");
+            }
+        }
+
+        #[test]
+        fn advanced_test() {
+            let primes = [1_000_000_007u128, 998_244_353, 1_000_000_009];
+            let x = Row::new_from_str("123456789012345678901234567").unwrap();
+
+            let nums = primes
+                .iter()
+                .map(|&p| {
+                    (
+                        crate::divrem(&x, &Row::new_from_num(p)).unwrap().1,
+                        Row::new_from_num(p),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let modulus = primes
+                .iter()
+                .fold(Row::unity(), |m, &p| crate::mul(&m, &Row::new_from_num(p)));
+            assert_eq!(Some((x, modulus)), crt(&nums));
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(proof(0, 1), crt(&[]));
+        }
+
+        #[test]
+        fn nought_modulus_test() {
+            assert_eq!(None, crt(&congruences(&[(1, 3), (0, 0)])));
+        }
+    }
+
+    mod sqrt_mod {
+        use crate::{sqrt_mod, Row};
 
-    total - takeover_val * 10
-}
+        #[test]
+        fn basic_test() {
+            assert_eq!(
+                Some(Row::new_from_num(2)),
+                sqrt_mod(&Row::new_from_num(4), &Row::new_from_num(7))
+            );
+            assert_eq!(
+                Some(Row::new_from_num(3)),
+                sqrt_mod(&Row::new_from_num(2), &Row::new_from_num(7))
+            );
+            assert_eq!(None, sqrt_mod(&Row::new_from_num(3), &Row::new_from_num(7)));
+        }
 
-#[cfg(test)]
-mod tests_of_units {
+        #[test]
+        fn universal_test() {
+            for p in [2u128, 3, 5, 13, 17, 41, 97, 193, 257, 353] {
+                for a in 0..p {
+                    let proof = (0..p).find(|x| x * x % p == a);
+                    let res = sqrt_mod(&Row::new_from_num(a), &Row::new_from_num(p));
+                    assert_eq!(proof.map(Row::new_from_num), res, "{a} {p}");
+                }
+            }
+        }
 
-    use crate::RawRow;
+        #[test]
+        fn advanced_test() {
+            // 2²⁵⁵ -19, p ≡ 5 (mod 8)
+            let p = Row::new_from_str(
+                "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+            )
+            .unwrap();
+            let a = Row::new_from_str("123456789123456789123456789").unwrap();
+            let square = crate::divrem(&crate::mul(&a, &a), &p).unwrap().1;
 
-    fn unity() -> RawRow {
-        [1].to_vec()
-    }
+            assert_eq!(Some(a), sqrt_mod(&square, &p));
+        }
 
-    fn nought() -> RawRow {
-        [0].to_vec()
+        #[test]
+        fn tonelli_shanks_test() {
+            // 2¹⁶ +1, p -1 = 2¹⁶
+            let p = Row::new_from_num(65_537);
+            let a = Row::new_from_num(12_345);
+            let square = crate::divrem(&crate::mul(&a, &a), &p).unwrap().1;
+
+            assert_eq!(Some(a), sqrt_mod(&square, &p));
+        }
+
+        #[test]
+        fn nought_unity_p_test() {
+            assert_eq!(None, sqrt_mod(&Row::unity(), &Row::nought()));
+            assert_eq!(None, sqrt_mod(&Row::unity(), &Row::unity()));
+        }
     }
 
-    mod placesrow {
-        use crate::Row;
-        use alloc::string::ToString;
+    mod sqrt_mod_prime_power {
+        use crate::{sqrt_mod_prime_power, Row};
+        use alloc::vec::Vec;
 
-        mod new_from_vec {
-            use crate::Row;
-            use alloc::vec;
+        fn roots(a: u128, p: u128, k: u16) -> Vec<u128> {
+            let pk = p.pow(k as u32);
+            match sqrt_mod_prime_power(&Row::new_from_num(a), &Row::new_from_num(p), k) {
+                None => Vec::new(),
+                Some((roots, n)) => {
+                    let n = n.to_number().parse::<u128>().unwrap();
+                    let roots = roots
+                        .iter()
+                        .map(|r| r.to_number().parse::<u128>().unwrap())
+                        .collect::<Vec<u128>>();
+
+                    assert_eq!(0, pk % n);
+                    assert!(roots.iter().all(|&r| r < n));
+
+                    (0..pk).filter(|x| roots.contains(&(x % n))).collect()
+                }
+            }
+        }
 
-            #[test]
-            fn basic_test() {
-                let row = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
-                let proof = row.clone();
-                let row = Row::new_from_vec(row);
+        #[test]
+        fn basic_test() {
+            let res = sqrt_mod_prime_power(&Row::new_from_num(2), &Row::new_from_num(7), 2);
+            let proof = (
+                alloc::vec![Row::new_from_num(10), Row::new_from_num(39)],
+                Row::new_from_num(49),
+            );
+            assert_eq!(Some(proof), res);
+        }
 
-                assert!(row.is_ok());
-                assert_eq!(proof, row.unwrap().row);
+        #[test]
+        fn universal_test() {
+            for (p, k) in [
+                (2u128, 1u16),
+                (2, 2),
+                (2, 3),
+                (2, 6),
+                (3, 3),
+                (5, 3),
+                (7, 2),
+                (3, 5),
+                (11, 2),
+            ] {
+                let pk = p.pow(k as u32);
+                for a in 0..pk {
+                    let proof = (0..pk).filter(|x| x * x % pk == a).collect::<Vec<u128>>();
+                    assert_eq!(proof, roots(a, p, k), "{a} {p}^{k}");
+                }
             }
+        }
 
-            #[test]
-            fn zero_len_test() {
-                let row = Row::new_from_vec(vec![0; 0]);
-                assert!(row.is_err());
-                assert_eq!(None, row.err().unwrap());
+        #[test]
+        fn advanced_test() {
+            let p = Row::new_from_num(1_000_000_007);
+            let a = Row::new_from_num(2);
+            let (roots, n) = sqrt_mod_prime_power(&a, &p, 5).unwrap();
+
+            assert_eq!(crate::pow(&p, 5), n);
+            assert_eq!(2, roots.len());
+            for r in roots {
+                assert_eq!(a, crate::divrem(&crate::mul(&r, &r), &n).unwrap().1);
             }
+        }
 
-            #[test]
-            fn unsupported_num_len_index_test() {
-                let row = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0];
-                let row = Row::new_from_vec(row);
+        #[test]
+        fn zero_exp_test() {
+            let res = sqrt_mod_prime_power(&Row::new_from_num(5), &Row::new_from_num(3), 0);
+            assert_eq!(Some((alloc::vec![Row::nought()], Row::unity())), res);
+        }
 
-                assert!(row.is_err());
-                assert_eq!(Some(10), row.err().unwrap());
-            }
+        #[test]
+        fn nought_unity_p_test() {
+            assert_eq!(None, sqrt_mod_prime_power(&Row::unity(), &Row::nought(), 2));
+            assert_eq!(None, sqrt_mod_prime_power(&Row::unity(), &Row::unity(), 2));
+        }
+    }
 
-            #[test]
-            fn unsupported_num_0_index_test() {
-                let row = vec![10, 0, 0, 0];
-                let row = Row::new_from_vec(row);
+    mod discrete_log {
+        use crate::{discrete_log, Row};
 
-                assert!(row.is_err());
-                assert_eq!(Some(0), row.err().unwrap());
-            }
+        #[test]
+        fn basic_test() {
+            assert_eq!(
+                Some(Row::new_from_num(3)),
+                discrete_log(
+                    &Row::new_from_num(2),
+                    &Row::new_from_num(8),
+                    &Row::new_from_num(11)
+                )
+            );
+            assert_eq!(
+                None,
+                discrete_log(
+                    &Row::new_from_num(4),
+                    &Row::new_from_num(3),
+                    &Row::new_from_num(7)
+                )
+            );
+        }
 
-            #[test]
-            fn leading_zeros_trim_test() {
-                let row = Row::new_from_vec(vec![1, 2, 0, 0]);
-                assert_eq!(&[1, 2], &*row.unwrap().row);
+        #[test]
+        fn universal_test() {
+            for p in [2u128, 3, 5, 7, 13, 31, 41] {
+                for g in 0..p {
+                    for h in 0..p {
+                        let mut proof = None;
+                        let mut power = 1 % p;
+                        for x in 0..p {
+                            if power == h {
+                                proof = Some(x);
+                                break;
+                            }
+
+                            power = power * g % p;
+                        }
+
+                        let res = discrete_log(
+                            &Row::new_from_num(g),
+                            &Row::new_from_num(h),
+                            &Row::new_from_num(p),
+                        );
+                        assert_eq!(proof.map(Row::new_from_num), res, "{g} {h} {p}");
+                    }
+                }
             }
+        }
 
-            #[test]
-            fn zero_reduction_test() {
-                let row = Row::new_from_vec(vec![0, 0, 0, 0]);
-                assert_eq!(&[0], &*row.unwrap().row);
-            }
+        #[test]
+        fn diffie_hellman_test() {
+            // 5 is primitive root modulo 10⁹ +7
+            let g = Row::new_from_num(5);
+            let h = Row::new_from_num(372_224_738);
+            let p = Row::new_from_num(1_000_000_007);
+
+            assert_eq!(
+                Some(Row::new_from_num(123_456_789)),
+                discrete_log(&g, &h, &p)
+            );
         }
 
         #[test]
-        fn new_from_num_test() {
-            let row = Row::new_from_num(000_1234567890);
-            assert_eq!(&[0, 9, 8, 7, 6, 5, 4, 3, 2, 1], &*row);
+        fn advanced_test() {
+            // p -1 = 2 ⋅3 ⋅5 ⋅11 ⋅17 ⋅23² ⋅29 ⋅41² ⋅53 ⋅59 ⋅61 ⋅73 ⋅79² ⋅89 ⋅97² ⋅101
+            let p = Row::new_from_str("1063344165686255356939376730511").unwrap();
+            let g = Row::new_from_num(5);
+            let h = Row::new_from_str("1004655023829825388903535192664").unwrap();
+
+            // order of 5 is 531672082843127678469688365255
+            let proof = Row::new_from_str("189460624093344028287873042695").unwrap();
+            assert_eq!(Some(proof), discrete_log(&g, &h, &p));
         }
 
-        mod new_from_str {
-            use crate::Row;
+        #[test]
+        fn nought_modulus_test() {
+            assert_eq!(
+                None,
+                discrete_log(&Row::unity(), &Row::unity(), &Row::nought())
+            );
+        }
+    }
 
-            #[test]
-            fn zero_len_test() {
-                let row = Row::new_from_str("");
-                assert!(row.is_err());
-                assert_eq!(None, row.err().unwrap());
-            }
+    mod pollard_rho_log {
+        use crate::{pollard_rho_log, Row};
 
-            #[test]
-            fn leading_zeros_trim_test() {
-                let row = Row::new_from_str("0021");
-                assert!(row.is_ok());
-                assert_eq!(&[1, 2], &*row.unwrap().row);
-            }
+        #[test]
+        fn basic_test() {
+            // safe prime 2q +1, 4 is of order q
+            let q = Row::new_from_num(1_000_151);
+            let p = Row::new_from_num(2_000_303);
+            let g = Row::new_from_num(4);
+            let h = Row::new_from_num(573_328);
+
+            assert_eq!(
+                Some(Row::new_from_num(777_777)),
+                pollard_rho_log(&g, &h, &q, &p)
+            );
+        }
+    }
 
-            #[test]
-            fn zeros_reduction_test() {
-                let row = Row::new_from_str("0000");
-                assert!(row.is_ok());
-                assert_eq!(&[0], &*row.unwrap().row);
-            }
+    mod factorize {
+        use crate::{factorize, Row};
+        use alloc::vec;
+        use alloc::vec::Vec;
 
-            #[test]
-            fn nondigit_str_test() {
-                let row = Row::new_from_str("0012w123");
-                assert!(row.is_err());
-                let inx = row.err().unwrap();
-                assert!(inx.is_some());
-                assert_eq!(4, inx.unwrap());
-            }
+        fn proof(factors: &[(u128, u32)]) -> Vec<(Row, u32)> {
+            factors
+                .iter()
+                .map(|f| (Row::new_from_num(f.0), f.1))
+                .collect()
+        }
 
-            #[test]
-            fn basic_test() {
-                let row = Row::new_from_str("1234567890");
-                assert!(row.is_ok());
-                assert_eq!(&[0, 9, 8, 7, 6, 5, 4, 3, 2, 1], &*row.unwrap().row);
-            }
+        #[test]
+        fn nought_unity_test() {
+            assert!(factorize(&Row::nought()).is_empty());
+            assert!(factorize(&Row::unity()).is_empty());
         }
 
-        mod to_number {
-            use crate::Row;
-            use alloc::vec;
+        #[test]
+        fn basic_test() {
+            let res = factorize(&Row::new_from_num(360));
+            assert_eq!(proof(&[(2, 3), (3, 2), (5, 1)]), res);
+        }
 
-            #[test]
-            fn basic_test() {
-                let row = Row::new_from_vec(vec![0, 9, 8, 7, 6, 5, 4, 3, 2, 1]).unwrap();
-                assert_eq!("1234567890", row.to_number().as_str());
+        #[test]
+        fn universal_test() {
+            for n in 2..2_000u128 {
+                let factors = factorize(&Row::new_from_num(n));
+
+                let mut product = 1;
+                for (f, e) in factors.iter() {
+                    let f = f.to_number().parse::<u128>().unwrap();
+                    assert!((2..f).take_while(|d| d * d <= f).all(|d| f % d != 0));
+                    product *= f.pow(*e);
+                }
+
+                assert_eq!(n, product);
             }
         }
 
-        use super::{nought, unity};
         #[test]
-        fn is_unity_test() {
-            let test = Row { row: unity() };
-            assert_eq!(true, test.is_unity());
+        fn large_prime_test() {
+            let p = 1_000_000_007;
+            assert_eq!(proof(&[(p, 1)]), factorize(&Row::new_from_num(p)));
         }
 
         #[test]
-        fn is_nought_test() {
-            let test = Row { row: nought() };
-            assert_eq!(true, test.is_nought());
+        fn rho_test() {
+            let res = factorize(&Row::new_from_num(1_000_003 * 1_000_033 * 10_007));
+            assert_eq!(proof(&[(10_007, 1), (1_000_003, 1), (1_000_033, 1)]), res);
         }
 
         #[test]
-        fn unity_test() {
-            let proof = Row { row: unity() };
-            assert_eq!(proof, Row::unity());
+        fn prime_power_test() {
+            let p = 1_000_000_007;
+            let res = factorize(&Row::new_from_num(p * p * 2));
+            assert_eq!(proof(&[(2, 1), (p, 2)]), res);
         }
 
         #[test]
-        fn nought_test() {
-            let proof = Row { row: nought() };
-            assert_eq!(proof, Row::nought());
+        fn ecm_test() {
+            // 40 places, factors of 20 places
+            let p = Row::new_from_str("10000000000000000051").unwrap();
+            let q = Row::new_from_str("31111111111111111177").unwrap();
+
+            let res = factorize(&crate::mul(&p, &q));
+            assert_eq!(vec![(p, 1), (q, 1)], res);
         }
 
         #[test]
-        #[allow(deprecated)]
-        fn zero_test() {
-            assert_eq!(&[0], &*Row::zero());
+        #[cfg(feature = "ext-tests")]
+        fn ecm_test2() {
+            // 49 places, factors of 25 places
+            let p = Row::new_from_str("1000000000000000000000007").unwrap();
+            let q = Row::new_from_str("3111111111111111111111151").unwrap();
+
+            let res = factorize(&crate::mul(&p, &q));
+            assert_eq!(vec![(p, 1), (q, 1)], res);
         }
+    }
+
+    mod factorize_bounded {
+        use crate::{factorize_bounded, Factorization, Row};
+        use alloc::vec;
 
         #[test]
-        fn to_string_test() {
-            let row = Row::new_from_num(1);
-            assert_eq!("1", row.to_string());
+        fn exhausted_effort_test() {
+            let composite = Row::new_from_num(1_000_003 * 1_000_033);
+            let res = factorize_bounded(&Row::new_from_num(1_000_003 * 1_000_033 * 12), 0);
+
+            let primes = vec![(Row::new_from_num(2), 2), (Row::new_from_num(3), 1)];
+            assert_eq!(Factorization::Partial(primes, vec![(composite, 1)]), res);
         }
 
         #[test]
-        fn from_test() {
-            let row: Row = From::from(123);
-            assert_eq!(&[3, 2, 1], &*row);
+        fn complete_test() {
+            let res = factorize_bounded(&Row::new_from_num(1_000_003 * 1_000_033), 100_000);
+
+            let primes = vec![
+                (Row::new_from_num(1_000_003), 1),
+                (Row::new_from_num(1_000_033), 1),
+            ];
+            assert_eq!(Factorization::Complete(primes), res);
         }
     }
 
-    mod shrink_to_fit_raw {
-        use crate::shrink_to_fit_raw;
-        use alloc::vec::Vec;
+    mod pollard_brent {
+        use crate::{pollard_brent, Montgomery, Row};
 
         #[test]
-        fn zero_truncation_test() {
-            let cap = 100;
-            let mut row = Vec::with_capacity(cap);
-            row.push(1);
-            row.push(2);
-            row.push(0);
+        fn basic_test() {
+            let mut effort = usize::MAX;
+            let ring = Montgomery::new(&Row::new_from_num(10_403));
+            let res = pollard_brent(&ring, 1, &mut effort);
+            assert!(res == Some(Row::new_from_num(101)) || res == Some(Row::new_from_num(103)));
+        }
 
-            shrink_to_fit_raw(&mut row);
-            assert_eq!(&[1, 2], &*row);
-            assert!(row.capacity() < cap);
+        #[test]
+        fn effort_test() {
+            let mut effort = 0;
+            let ring = Montgomery::new(&Row::new_from_num(1_000_003 * 1_000_033));
+            let res = pollard_brent(&ring, 1, &mut effort);
+            assert_eq!(None, res);
+        }
+
+        // round length r in which factor of `n` is found, x² +1 from 2
+        fn found_round(n: u128) -> usize {
+            let f = |x: u128| (x * x + 1) % n;
+            let gcd = |mut a: u128, mut b: u128| {
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            };
+
+            let (mut y, mut q, mut r) = (2, 1, 1);
+            loop {
+                let x = y;
+                for _ in 0..r {
+                    y = f(y);
+                }
+
+                let mut k = 0;
+                while k < r {
+                    for _ in 0..128.min(r - k) {
+                        y = f(y);
+                        q = q * x.abs_diff(y) % n;
+                    }
+
+                    if gcd(q, n) != 1 {
+                        return r;
+                    }
+
+                    k += 128;
+                }
+
+                r *= 2;
+            }
         }
 
         #[test]
-        fn first_preservation_test() {
-            let cap = 100;
-            let mut row = Vec::with_capacity(cap);
-            row.push(0);
-            row.push(0);
-            row.push(0);
+        fn last_round_test() {
+            let n = 1_000_003 * 1_000_033;
+            let ring = Montgomery::new(&Row::new_from_num(n));
+
+            // each round of length r after which search continues costs 4r
+            let cost = 4 * (found_round(n) - 1);
+
+            let mut effort = cost;
+            let res = pollard_brent(&ring, 1, &mut effort);
+            assert!(
+                res == Some(Row::new_from_num(1_000_003))
+                    || res == Some(Row::new_from_num(1_000_033))
+            );
+            assert_eq!(0, effort);
+
+            let mut effort = cost - 1;
+            assert_eq!(None, pollard_brent(&ring, 1, &mut effort));
+        }
+    }
 
-            shrink_to_fit_raw(&mut row);
-            assert_eq!(&[0], &*row);
-            assert!(row.capacity() < cap);
+    mod ecm {
+        use crate::{ecm, primes_below, Montgomery, Row};
+
+        #[test]
+        fn basic_test() {
+            let ring = Montgomery::new(&Row::new_from_num(1_000_003 * 1_000_033));
+            let primes = primes_below(500);
+            let mut effort = usize::MAX;
+
+            let factor = (6..30).find_map(|sigma| ecm(&ring, sigma, &primes, &mut effort));
+            assert!(
+                factor == Some(Row::new_from_num(1_000_003))
+                    || factor == Some(Row::new_from_num(1_000_033))
+            );
         }
     }
 
-    mod truncate_leading_raw {
-        use crate::truncate_leading_raw;
+    mod limbs_from_row {
+        use crate::{limbs_from_row, pow, row_from_limbs, sub, Row};
         use alloc::vec;
 
         #[test]
         fn basic_test() {
-            let mut row = vec![7, 7, 7, 7, 7];
-            truncate_leading_raw(&mut row, 7, 3);
-            assert_eq!(vec![7, 7, 7,], row);
+            assert_eq!(vec![0], limbs_from_row(&Row::nought(), 0));
+            assert_eq!(vec![0, 0, 0], limbs_from_row(&Row::nought(), 3));
+            assert_eq!(
+                vec![u64::MAX],
+                limbs_from_row(&Row::new_from_num(u64::MAX as u128), 0)
+            );
+            assert_eq!(vec![0, 1], limbs_from_row(&Row::new_from_num(1 << 64), 0));
+            assert_eq!(vec![5, 0, 0], limbs_from_row(&Row::new_from_num(5), 3));
+
+            let num = Row::new_from_num(u128::MAX);
+            assert_eq!(vec![u64::MAX, u64::MAX], limbs_from_row(&num, 0));
+        }
+
+        #[test]
+        fn round_trip_test() {
+            let two = Row::new_from_num(2);
+            let mut nums = vec![
+                Row::nought(),
+                Row::unity(),
+                Row::new_from_num(9_999_999_999_999_999_999),
+                Row::new_from_num(10_000_000_000_000_000_000),
+                Row::new_from_str("123456789012345678901234567890123456789012345678901").unwrap(),
+                pow(&Row::new_from_num(10), 1_000),
+            ];
+
+            for bits in [64, 128, 192, 640] {
+                let power = pow(&two, bits);
+                nums.push(sub(&power, &Row::unity()).unwrap());
+                nums.push(power);
+            }
+
+            for num in nums {
+                assert_eq!(num, row_from_limbs(&limbs_from_row(&num, 0)));
+                assert_eq!(num, row_from_limbs(&limbs_from_row(&num, 20)));
+            }
         }
     }
 
-    mod len_without_leading_raw {
-        use crate::len_without_leading_raw;
-        use alloc::vec;
+    mod row_from_limbs {
+        use crate::{row_from_limbs, Row};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Row::nought(), row_from_limbs(&[]));
+            assert_eq!(Row::nought(), row_from_limbs(&[0, 0]));
+            assert_eq!(Row::new_from_num(7), row_from_limbs(&[7, 0, 0]));
+            assert_eq!(Row::new_from_num(1 << 64), row_from_limbs(&[0, 1]));
+            assert_eq!(
+                Row::new_from_num(u128::MAX),
+                row_from_limbs(&[u64::MAX, u64::MAX])
+            );
+        }
+    }
+
+    mod montgomery {
+        use crate::{add, limbs_from_row, mul, pow, rem, row_from_limbs, sub, Montgomery, Row};
+
+        // a ⋅b ⋅R⁻¹ with b = 1 leaves Montgomery form
+        fn mont_mul(ring: &Montgomery, a: &Row, b: &Row) -> Row {
+            let prod = ring.mul(&ring.to_mont(a), &ring.to_mont(b));
+            let unity = limbs_from_row(&Row::unity(), ring.modulus.len());
+            row_from_limbs(&ring.mul(&prod, &unity))
+        }
 
         #[test]
-        fn counting_test() {
-            let count = len_without_leading_raw(&vec![1, 2, 5, 5, 5], 5, 0);
-            assert_eq!(2, count);
+        fn long_modulus_test() {
+            // 10⁹⁹⁰⁰ +1 spans 514 limbs
+            let ten = Row::new_from_num(10);
+            let num = add(&pow(&ten, 9_900), &Row::unity());
+            let ring = Montgomery::new(&num);
+            assert!(ring.modulus.len() > 512);
+
+            let a = add(&pow(&ten, 4_000), &Row::new_from_num(12_345));
+            let b = pow(&Row::new_from_num(3), 8_000);
+            assert_eq!(mul(&a, &b), mont_mul(&ring, &a, &b));
         }
 
         #[test]
-        fn preservation_test() {
-            let count = len_without_leading_raw(&vec![5, 5, 5, 5], 5, 1);
-            assert_eq!(1, count);
+        fn mul_test() {
+            let two = Row::new_from_num(2);
+            let moduli = [
+                Row::new_from_num(3),
+                Row::new_from_num(1_000_003 * 1_000_033),
+                Row::new_from_num(u64::MAX as u128),
+                Row::new_from_num(u128::MAX),
+                // 2²⁵⁶ -1 has all limbs full, every addition carries
+                sub(&pow(&two, 256), &Row::unity()).unwrap(),
+                add(&pow(&two, 255), &Row::unity()),
+                Row::new_from_str("99999999999999999999999999999999999999999999999999999").unwrap(),
+            ];
+
+            for n in moduli {
+                let ring = Montgomery::new(&n);
+                let n_1 = sub(&n, &Row::unity()).unwrap();
+                let n_2 = sub(&n, &two).unwrap_or(Row::nought());
+                let half = crate::divrem(&n, &two).unwrap().0;
+
+                for (a, b) in [
+                    (Row::nought(), n_1.clone()),
+                    (Row::unity(), n_1.clone()),
+                    (n_1.clone(), n_1.clone()),
+                    (n_1.clone(), n_2.clone()),
+                    (half.clone(), n_1.clone()),
+                    (half.clone(), half.clone()),
+                    (
+                        Row::new_from_num(2),
+                        rem(&Row::new_from_num(12_345_678_901), &n),
+                    ),
+                ] {
+                    let proof = rem(&mul(&a, &b), &n);
+                    assert_eq!(proof, mont_mul(&ring, &a, &b), "{n:?}");
+                }
+            }
         }
+    }
+
+    mod effort_spend {
+        use crate::effort_spend;
 
         #[test]
-        fn no_leading_test() {
-            let count = len_without_leading_raw(&vec![5, 5, 5, 0], 5, 0);
-            assert_eq!(4, count);
+        fn basic_test() {
+            let mut effort = 10;
+            assert!(effort_spend(&mut effort, 4));
+            assert_eq!(6, effort);
+
+            assert!(effort_spend(&mut effort, 6));
+            assert_eq!(0, effort);
+
+            assert!(!effort_spend(&mut effort, 1));
+            assert_eq!(0, effort);
         }
 
         #[test]
-        fn upto_equal_len_test() {
-            let count = len_without_leading_raw(&vec![5, 5, 5], 5, 3);
-            assert_eq!(3, count);
+        fn insufficient_test() {
+            let mut effort = 3;
+            assert!(!effort_spend(&mut effort, 4));
+            assert_eq!(0, effort);
         }
-    }
 
-    use crate::{is_nought_raw, is_unity_raw, nought_raw, unity_raw};
-    #[test]
-    fn unity_raw_test() {
-        assert_eq!(unity(), unity_raw());
+        #[test]
+        fn unlimited_test() {
+            let mut effort = usize::MAX;
+            assert!(effort_spend(&mut effort, usize::MAX));
+            assert_eq!(usize::MAX, effort);
+        }
     }
 
-    #[test]
-    fn nought_raw_test() {
-        assert_eq!(nought(), nought_raw());
-    }
+    mod divrem_num_raw {
+        use crate::{divrem_num_raw, Row};
 
-    #[test]
-    fn is_unity_raw_test() {
-        assert_eq!(true, is_unity_raw(&unity()));
-    }
+        #[test]
+        fn basic_test() {
+            let row = Row::new_from_num(1_234_567_890_123);
+            let (ratio, rem) = divrem_num_raw(&row.row, 1_000_003);
 
-    #[test]
-    fn is_nought_raw_test() {
-        assert_eq!(true, is_nought_raw(&nought()));
+            assert_eq!(Row::new_from_num(1_234_567_890_123 / 1_000_003).row, ratio);
+            assert_eq!(1_234_567_890_123 % 1_000_003, rem);
+        }
     }
 
-    mod is_one_raw_test {
-        use crate::is_one_raw;
+    mod primes_below {
+        use crate::primes_below;
 
         #[test]
-        fn is() {
-            let test = [3].to_vec();
-            assert_eq!(true, is_one_raw(&test, 3));
+        fn basic_test() {
+            assert_eq!(&[2, 3, 5, 7, 11, 13, 17, 19], &*primes_below(20));
+            assert_eq!(1229, primes_below(10_000).len());
         }
+    }
 
-        #[test]
-        fn different() {
-            let test = [3].to_vec();
-            assert_eq!(false, is_one_raw(&test, 4));
-        }
+    mod lcm {
+        use crate::{lcm, Row};
 
         #[test]
-        fn longer() {
-            let test = [3, 3].to_vec();
-            assert_eq!(false, is_one_raw(&test, 3));
+        fn basic_test() {
+            for (a, b, proof) in [(4, 6, 12), (7, 13, 91), (0, 5, 0), (5, 0, 0), (12, 12, 12)] {
+                let res = lcm(&Row::new_from_num(a), &Row::new_from_num(b));
+                assert_eq!(Row::new_from_num(proof), res, "{a} {b}");
+            }
         }
     }
 
-    mod from_digit {
-        extern crate std;
+    mod totient {
+        use crate::{factorize, totient, Row};
 
-        use crate::from_digit;
-        use alloc::format;
-        use alloc::string::{String, ToString};
-        use std::panic::catch_unwind;
+        fn gcd(a: u128, b: u128) -> u128 {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
 
         #[test]
-        fn basic_test() {
-            for proof in 0..=9 {
-                let test = from_digit(proof.to_string().chars().next().unwrap());
-                assert_eq!(proof, test);
+        fn universal_test() {
+            for n in 1..500u128 {
+                let proof = (1..=n).filter(|&k| gcd(k, n) == 1).count() as u128;
+                let factors = factorize(&Row::new_from_num(n));
+                assert_eq!(Row::new_from_num(proof), totient(&factors), "{n}");
             }
         }
 
         #[test]
-        fn unsupported_char_test() {
-            let uc = ['0' as u8 - 1, '9' as u8 + 1];
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime, φ(p²) = p ⋅(p -1)
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            let factors = [(p.clone(), 2)];
 
-            for c in uc {
-                let c = c as char;
+            let p_less = crate::sub(&p, &Row::unity()).unwrap();
+            assert_eq!(crate::mul(&p, &p_less), totient(&factors));
+        }
+    }
 
-                let proof = format!("Unsupported char `{c}` conversion.");
-                let catch = catch_unwind(|| from_digit(c));
+    mod carmichael {
+        use crate::{carmichael, factorize, Row};
 
-                assert!(catch.is_err());
-                let err = catch.unwrap_err().downcast::<String>().unwrap();
-                assert_eq!(proof, *err);
+        fn gcd(a: u128, b: u128) -> u128 {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        #[test]
+        fn universal_test() {
+            for n in 1..300u128 {
+                // least m, aᵐ ≡ 1 (mod n) for all a coprime to n
+                let proof = (1..=n)
+                    .find(|&m| {
+                        (1..=n).filter(|&a| gcd(a, n) == 1).all(|a| {
+                            let mut p = 1;
+                            for _ in 0..m {
+                                p = p * a % n;
+                            }
+                            p == 1 % n
+                        })
+                    })
+                    .unwrap();
+
+                let factors = factorize(&Row::new_from_num(n));
+                assert_eq!(Row::new_from_num(proof), carmichael(&factors), "{n}");
             }
         }
     }
 
-    mod to_digit {
-        use crate::to_digit;
-        use alloc::string::ToString;
+    mod divisor_count {
+        use crate::{divisor_count, factorize, Row};
 
         #[test]
-        fn basic_test() {
-            for n in 0..=9 {
-                let test = to_digit(n);
-                let proof = n.to_string().chars().next().unwrap();
-                assert_eq!(proof, test);
+        fn universal_test() {
+            for n in 1..500u128 {
+                let proof = (1..=n).filter(|d| n % d == 0).count() as u128;
+                let factors = factorize(&Row::new_from_num(n));
+                assert_eq!(Row::new_from_num(proof), divisor_count(&factors), "{n}");
             }
         }
 
         #[test]
-        #[should_panic(expected = "Only number < 10 supported.")]
-        fn less_than_10_support_only_test() {
-            to_digit(10);
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert_eq!(Row::new_from_num(3), divisor_count(&[(p, 2)]));
         }
     }
 
-    mod ord_of_mag {
+    mod divisor_sum {
+        use crate::{divisor_sum, factorize, Row};
+        use alloc::vec::Vec;
 
-        use crate::{ord_of_mag, Oom, OomKind, PlacesRow, Row};
+        #[test]
+        fn universal_test() {
+            for n in 1..500u128 {
+                let divs = (1..=n).filter(|d| n % d == 0).collect::<Vec<u128>>();
+                let factors = factorize(&Row::new_from_num(n));
 
-        const PROOF: &str = "3162277660168379331998893544432718533719555139325216826857504852792594438639238221344248108379300295187347284152840055148548856030453880014690519596700153903344921657179259940659150153474113339484124085316929577090471576461044369257879062037808609941828371711548406328552999118596824564203326961604691314336128949791890266529543612676178781350061388186278580463683134952478031143769334671973819513185678403231241795402218308045872844614600253577579702828644029024407977896034543989163349222652612067792651676031048436697793756926155720500369894909469421850007358348844643882731109289109042348054235653403907274019786543725939641726001306990000955784463109626790694418336130181302894541703315807731626386395193793704654765220632063686587197822049312426053454111609356979828132452297000798883523759585328579251362964686511497675217123459559238039375625125369855194955325099947038843990336466165470647234999796132343403021857052187836676345789510732982875157945215771652139626324438399018484560935762602";
+                let count = Row::new_from_num(divs.len() as u128);
+                assert_eq!(count, divisor_sum(&factors, 0), "{n}");
 
-        #[test]
-        fn consts_test() {
-            use crate::SQUARE_ROOT_TEN_COMPARATOR;
+                let sum = Row::new_from_num(divs.iter().sum());
+                assert_eq!(sum, divisor_sum(&factors, 1), "{n}");
 
-            assert_eq!(1000, SQUARE_ROOT_TEN_COMPARATOR.len());
-            assert_eq!(PROOF, SQUARE_ROOT_TEN_COMPARATOR);
+                let sum = Row::new_from_num(divs.iter().map(|d| d * d * d).sum());
+                assert_eq!(sum, divisor_sum(&factors, 3), "{n}");
+            }
         }
+    }
 
-        #[test]
-        fn nought_test() {
-            let r = Row::nought();
-            let oom = unsafe { core::mem::transmute::<u8, OomKind>(u8::MAX) };
+    mod mobius {
+        use crate::{factorize, mobius, Row};
 
-            assert_eq!(Oom::Undefined, ord_of_mag(&r, oom));
+        #[test]
+        fn basic_test() {
+            for (n, proof) in [(1, 1), (2, -1), (4, 0), (6, 1), (30, -1), (210, 1), (12, 0)] {
+                let factors = factorize(&Row::new_from_num(n));
+                assert_eq!(proof, mobius(&factors), "{n}");
+            }
         }
 
         #[test]
-        fn readme_sample_test() {
-            let number_1 = PlacesRow::new_from_num(3162277660168379331998893544432);
-            let number_2 = PlacesRow::new_from_num(3162277660168379331998893544433);
-
-            assert_eq!(Oom::Precise(30), ord_of_mag(&number_1, OomKind::Strict));
-            assert_eq!(Oom::Precise(31), ord_of_mag(&number_2, OomKind::Strict));
-            assert_eq!(Oom::Precise(30), ord_of_mag(&number_2, OomKind::Loose));
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert_eq!(0, mobius(&[(p, 2)]));
         }
+    }
 
-        mod strict {
-            use alloc::string::String;
+    mod divisors {
+        use crate::{divisors, factorize, Row};
+        use alloc::vec::Vec;
 
-            use super::PROOF;
-            use crate::{ord_of_mag, Oom, OomKind::Strict, Row};
+        #[test]
+        fn universal_test() {
+            for n in 1..300u128 {
+                let factors = factorize(&Row::new_from_num(n));
+                let mut res = divisors(&factors)
+                    .map(|d| d.to_number().parse::<u128>().unwrap())
+                    .collect::<Vec<u128>>();
+                res.sort();
+
+                let proof = (1..=n).filter(|d| n % d == 0).collect::<Vec<u128>>();
+                assert_eq!(proof, res, "{n}");
+            }
+        }
 
-            #[test]
-            fn universal_test() {
-                #[rustfmt::skip]
-                // lesser-shorter-greater triplets
-                let values = [
-                    (2,0), (3, 0), (4, 1),
-                    (30, 1), (31, 1), (32, 2),                    
-                    (315, 2), (316, 2), (317, 3), 
-                    (3161, 3), (3162, 3), (3163, 4),                       
-                    (316_227_765, 8), (316_227_766, 8), (316_227_767, 9),                                   
-                ];
+        #[test]
+        fn advanced_test() {
+            // 2¹²⁷ -1 is prime
+            let p = Row::new_from_str("170141183460469231731687303715884105727").unwrap();
+            assert_eq!(3, divisors(&[(p, 2)]).count());
+        }
+    }
 
-                for v in values {
-                    let r = Row::new_from_num(v.0);
-                    let o = ord_of_mag(&r, Strict);
+    mod isqrt {
+        use crate::{isqrt, Row};
 
-                    assert_eq!(Oom::Precise(v.1), o, "{:?}", v);
+        #[test]
+        fn universal_test() {
+            for num in (0..2_000u128).chain([u128::MAX, 10u128.pow(30), 10u128.pow(30) - 1]) {
+                let mut root = (num as f64).sqrt() as u128;
+                while root.checked_mul(root).is_none_or(|sq| sq > num) {
+                    root -= 1;
+                }
+                while (root + 1).checked_mul(root + 1).is_some_and(|sq| sq <= num) {
+                    root += 1;
                 }
-            }
-
-            #[test]
-            fn full_precision_test() {
-                let r = Row::new_from_str(PROOF).unwrap();
-                let o = ord_of_mag(&r, Strict);
 
-                assert_eq!(Oom::Precise(PROOF.len()), o);
+                let proof = (
+                    Row::new_from_num(root),
+                    Row::new_from_num(num - root * root),
+                );
+                assert_eq!(proof, isqrt(&Row::new_from_num(num)), "{num}");
             }
+        }
 
-            #[test]
-            fn behind_precision_test() {
-                let mut proof = String::from(PROOF);
-                proof.push('0');
+        #[test]
+        fn advanced_test() {
+            let root = Row::new_from_str("3162277660168379331998893544432718533719").unwrap();
+            let num = crate::mul(&root, &root);
+            let num = crate::add(&num, &Row::new_from_num(100));
 
-                let r = Row::new_from_str(proof.as_str()).unwrap();
-                let o = ord_of_mag(&r, Strict);
+            assert_eq!((root, Row::new_from_num(100)), isqrt(&num));
+        }
+    }
 
-                let proof_len = proof.len();
-                assert_eq!(1001, proof_len);
-                assert_eq!(Oom::Approx(proof_len), o);
-            }
+    mod iroot {
+        use crate::{iroot, pow, Row};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(
+                Some((Row::new_from_num(3), true)),
+                iroot(&Row::new_from_num(27), 3)
+            );
+            assert_eq!(
+                Some((Row::new_from_num(3), false)),
+                iroot(&Row::new_from_num(63), 3)
+            );
+            assert_eq!(
+                Some((Row::new_from_num(4), true)),
+                iroot(&Row::new_from_num(64), 3)
+            );
         }
 
-        mod loose {
-            use crate::{ord_of_mag, Oom, OomKind::Loose, Row};
+        #[test]
+        fn square_test() {
+            assert_eq!(
+                Some((Row::new_from_num(12), false)),
+                iroot(&Row::new_from_num(168), 2)
+            );
+            assert_eq!(
+                Some((Row::new_from_num(13), true)),
+                iroot(&Row::new_from_num(169), 2)
+            );
+        }
 
-            #[test]
-            fn universal_test() {
-                #[rustfmt::skip]
-                let values = [                    
-                    // lesser-equal-greater triplet
-                    (4, 0)    , (5, 1)     , (6, 1),                    
-                    // lesser-longer-greater triplets
-                    (40, 1)   , (50, 2)    , (60, 2),                                                                                                                        
-                    (4000, 3) , (5000, 4)  , (6000, 4)                                                                                                                                                                
-                ];
+        #[test]
+        fn universal_test() {
+            for n in 3..9u16 {
+                for base in [2u128, 7, 10, 99, 1234] {
+                    let num = pow(&Row::new_from_num(base), n);
+                    let less = crate::sub(&num, &Row::unity()).unwrap();
 
-                for v in values {
-                    let r = Row::new_from_num(v.0);
-                    let o = ord_of_mag(&r, Loose);
+                    let proof = Some((Row::new_from_num(base), true));
+                    assert_eq!(proof, iroot(&num, n), "{base}^{n}");
 
-                    assert_eq!(Oom::Precise(v.1), o, "{:?}", v);
+                    let proof = Some((Row::new_from_num(base - 1), false));
+                    assert_eq!(proof, iroot(&less, n), "{base}^{n} -1");
                 }
             }
         }
-    }
-
-    // Relational comparison.
-    mod rel {
-        use crate::{rel, Rel, Row};
 
         #[test]
-        fn basic_test() {
-            let num = Row::new_from_num(155);
-            let comparand = Row::new_from_num(155);
-
-            assert_eq!(Rel::Equal, rel(&num, &comparand));
+        fn zero_degree_test() {
+            assert_eq!(None, iroot(&Row::new_from_num(27), 0));
         }
     }
 
-    mod rel_raw {
-
-        use crate::{rel_raw, Rel, Row};
+    mod iroot_shortcut {
+        use crate::{iroot_shortcut, Row};
 
         #[test]
-        fn longer_test() {
-            let num = Row::new_from_num(11).row;
-            let comparand = Row::new_from_num(9).row;
+        fn zero_degree_test() {
+            assert_eq!(Some(None), iroot_shortcut(&Row::unity(), 0));
+        }
 
-            let proof = Rel::Greater(Some((2, 1, 1)));
-            assert_eq!(proof, rel_raw(&num, &comparand));
+        #[test]
+        fn first_degree_test() {
+            let num = Row::new_from_num(1234);
+            assert_eq!(Some(Some((num.clone(), true))), iroot_shortcut(&num, 1));
         }
 
         #[test]
-        fn shorter_test() {
-            let num = Row::new_from_num(9).row;
-            let comparand = Row::new_from_num(10).row;
+        fn nought_test() {
+            let num = Row::nought();
+            assert_eq!(Some(Some((num.clone(), true))), iroot_shortcut(&num, 7));
+        }
 
-            let proof = Rel::Lesser(Some((1, 2, 1)));
-            assert_eq!(proof, rel_raw(&num, &comparand));
+        #[test]
+        fn unity_test() {
+            let num = Row::unity();
+            assert_eq!(Some(Some((num.clone(), true))), iroot_shortcut(&num, 7));
         }
 
         #[test]
-        fn greater_test() {
-            let num_num = 1234567899;
-            let cpd_num = 1234567890;
+        fn high_degree_test() {
+            let num = Row::new_from_num(1234);
+            assert_eq!(Some(Some((Row::unity(), false))), iroot_shortcut(&num, 16));
+            assert_eq!(None, iroot_shortcut(&num, 15));
+        }
+    }
 
-            let num = Row::new_from_num(num_num).row;
-            let comparand = Row::new_from_num(cpd_num).row;
+    mod is_perfect_square {
+        use crate::{is_perfect_square, Row};
 
-            assert_eq!(Rel::Greater(None), rel_raw(&num, &comparand));
+        #[test]
+        fn universal_test() {
+            for n in 0..5_000u128 {
+                let proof = (0..=n).take_while(|x| x * x <= n).any(|x| x * x == n);
+                assert_eq!(proof, is_perfect_square(&Row::new_from_num(n)), "{n}");
+            }
         }
 
         #[test]
-        fn equal_test() {
-            let num = Row::new_from_num(1234567890);
-            assert_eq!(Rel::Equal, rel_raw(&num.row, &num.row));
+        fn advanced_test() {
+            let root = Row::new_from_str("31622776601683793319988935444327").unwrap();
+            let square = crate::mul(&root, &root);
+            assert!(is_perfect_square(&square));
+            assert!(!is_perfect_square(&crate::add(&square, &Row::unity())));
         }
+    }
+
+    mod is_perfect_power {
+        use crate::{is_perfect_power, pow, Row};
 
         #[test]
-        fn lesser_test() {
-            let num_num = 1234567890;
-            let cpd_num = 1234567899;
+        fn basic_test() {
+            let proof = Some((Row::new_from_num(2), 6));
+            assert_eq!(proof, is_perfect_power(&Row::new_from_num(64)));
 
-            let num = Row::new_from_num(num_num).row;
-            let comparand = Row::new_from_num(cpd_num).row;
+            let proof = Some((Row::new_from_num(6), 2));
+            assert_eq!(proof, is_perfect_power(&Row::new_from_num(36)));
 
-            assert_eq!(Rel::Lesser(None), rel_raw(&num, &comparand));
+            assert_eq!(None, is_perfect_power(&Row::new_from_num(72)));
         }
 
         #[test]
-        fn both_nought_test() {
-            let num = Row::new_from_num(0).row;
+        fn universal_test() {
+            for n in 2..3_000u128 {
+                let mut proof = None;
+                for e in (2..12u32).rev() {
+                    if let Some(b) = (2..=n)
+                        .take_while(|b| b.pow(e) <= n)
+                        .find(|b| b.pow(e) == n)
+                    {
+                        proof = Some((Row::new_from_num(b), e));
+                        break;
+                    }
+                }
 
-            assert_eq!(Rel::Equal, rel_raw(&num, &num));
+                assert_eq!(proof, is_perfect_power(&Row::new_from_num(n)), "{n}");
+            }
         }
-    }
-
-    mod rel_dec {
-        use crate::{rel_dec, RelDec, Row};
 
         #[test]
-        fn basic_test() {
-            let num = Row::new_from_num(9876543210);
-
-            assert_eq!(RelDec::Equal(10), rel_dec(&num, &num));
+        fn advanced_test() {
+            // 12³⁰ = 1728¹⁰ = 144¹⁵
+            let num = pow(&Row::new_from_num(1728), 10);
+            assert_eq!(Some((Row::new_from_num(12), 30)), is_perfect_power(&num));
+
+            let num = pow(&Row::new_from_num(u64::MAX as u128), 7);
+            assert_eq!(
+                Some((Row::new_from_num(u64::MAX as u128), 7)),
+                is_perfect_power(&num)
+            );
         }
 
         #[test]
-        #[rustfmt::skip]
-        fn readme_sample_test() {
-            let number    = Row::new_from_str("1489754132134687989463132131").unwrap();
-            let comparand = Row::new_from_str(        "48645698946456531371").unwrap();
-            let decrel = rel_dec(&number, &comparand);
+        fn near_power_test() {
+            let num = pow(&Row::new_from_num(99_991), 5);
+            let num = crate::add(&num, &Row::unity());
+            assert_eq!(None, is_perfect_power(&num));
+        }
 
-            assert_eq!(RelDec::Greater((28, 20, 8)), decrel);
+        #[test]
+        fn nought_unity_test() {
+            assert_eq!(None, is_perfect_power(&Row::nought()));
+            assert_eq!(None, is_perfect_power(&Row::unity()));
         }
     }
 
-    mod rel_dec_raw {
-        use crate::{rel_dec_raw, RelDec, Row};
+    mod power_residue_filter {
+        use crate::{power_residue_filter, Row};
 
         #[test]
-        fn equal_test() {
-            let num = Row::new_from_num(9876543210).row;
+        fn basic_test() {
+            // 10 ≡ 3 (mod 7) is not cubic residue
+            assert!(power_residue_filter(&Row::new_from_num(27), 3));
+            assert!(!power_residue_filter(&Row::new_from_num(10), 3));
+            // squares do not end with 2
+            assert!(!power_residue_filter(&Row::new_from_num(1_000_002), 2));
+        }
+    }
 
-            assert_eq!(RelDec::Equal(10), rel_dec_raw(&num, &num));
+    mod continued_fraction {
+        use crate::{continued_fraction, Row};
+
+        #[test]
+        fn basic_test() {
+            let terms = continued_fraction(&Row::new_from_num(415), &Row::new_from_num(93));
+            let proof = [4u128, 2, 6, 7].map(Row::new_from_num);
+            assert_eq!(Some(proof.to_vec()), terms);
         }
 
         #[test]
-        fn lesser_test() {
-            let num = Row::new_from_num(10).row;
-            let comparand = Row::new_from_num(9876543210).row;
+        fn integer_test() {
+            let terms = continued_fraction(&Row::new_from_num(42), &Row::unity());
+            assert_eq!(Some([Row::new_from_num(42)].to_vec()), terms);
 
-            let proof = RelDec::Lesser((2, 10, 8));
-            assert_eq!(proof, rel_dec_raw(&num, &comparand));
+            let terms = continued_fraction(&Row::nought(), &Row::new_from_num(7));
+            assert_eq!(Some([Row::nought()].to_vec()), terms);
         }
 
         #[test]
-        fn greater_test() {
-            let num = Row::new_from_num(9876543210).row;
-            let comparand = Row::new_from_num(10).row;
+        fn proper_fraction_test() {
+            let terms = continued_fraction(&Row::new_from_num(3), &Row::new_from_num(8));
+            let proof = [0u128, 2, 1, 2].map(Row::new_from_num);
+            assert_eq!(Some(proof.to_vec()), terms);
+        }
 
-            let proof = RelDec::Greater((10, 2, 8));
-            assert_eq!(proof, rel_dec_raw(&num, &comparand));
+        #[test]
+        fn nought_den_test() {
+            assert_eq!(None, continued_fraction(&Row::unity(), &Row::nought()));
         }
 
         #[test]
-        fn nought_test() {
-            let num = Row::new_from_num(0).row;
+        fn advanced_test() {
+            // π ≈ 3.1415926535897932384626433832795028841971
+            let num = Row::new_from_str("31415926535897932384626433832795028841971").unwrap();
+            let den = Row::new_from_str("10000000000000000000000000000000000000000").unwrap();
 
-            assert_eq!(RelDec::Equal(0), rel_dec_raw(&num, &num));
+            let terms = continued_fraction(&num, &den).unwrap();
+            let proof = [3u128, 7, 15, 1, 292, 1, 1, 1, 2, 1, 3, 1].map(Row::new_from_num);
+            assert_eq!(proof.as_slice(), &terms[..12]);
         }
     }
 
-    // Addition.
-    mod add {
-        use crate::{add, Row};
+    mod convergents {
+        use crate::{convergents, Row};
+        use alloc::vec::Vec;
 
         #[test]
         fn basic_test() {
-            let row1 = Row::new_from_num(4);
-            let row2 = Row::new_from_num(5);
+            let terms = [4u128, 2, 6, 7].map(Row::new_from_num);
+            let proof = [(4u128, 1u128), (9, 2), (58, 13), (415, 93)]
+                .map(|(p, q)| (Row::new_from_num(p), Row::new_from_num(q)));
+
+            assert_eq!(
+                proof.as_slice(),
+                convergents(&terms).collect::<Vec<(Row, Row)>>()
+            );
+        }
 
-            let sum = add(&row1, &row2);
-            assert_eq!(&[9], &*sum.row);
+        #[test]
+        fn pi_test() {
+            let terms = [3u128, 7, 15, 1].map(Row::new_from_num);
+            let proof = [(3u128, 1u128), (22, 7), (333, 106), (355, 113)]
+                .map(|(p, q)| (Row::new_from_num(p), Row::new_from_num(q)));
+
+            assert_eq!(
+                proof.as_slice(),
+                convergents(&terms).collect::<Vec<(Row, Row)>>()
+            );
         }
 
         #[test]
-        fn left_num_longer_test() {
-            let row1 = Row::new_from_num(10_000);
-            let row2 = Row::new_from_num(5);
+        fn empty_test() {
+            assert_eq!(0, convergents(&[]).count());
+        }
+    }
 
-            let sum = add(&row1, &row2);
-            assert_eq!(Row::new_from_num(10_005), sum);
+    mod sqrt_continued_fraction {
+        use crate::{sqrt_continued_fraction, Row};
+        use alloc::vec::Vec;
+
+        #[test]
+        fn basic_test() {
+            for (n, a0, period) in [
+                (2u128, 1u128, [2u128].as_slice()),
+                (7, 2, &[1, 1, 1, 4]),
+                (13, 3, &[1, 1, 1, 1, 6]),
+                (94, 9, &[1, 2, 3, 1, 1, 5, 1, 8, 1, 5, 1, 1, 3, 2, 1, 18]),
+            ] {
+                let period = period
+                    .iter()
+                    .map(|&a| Row::new_from_num(a))
+                    .collect::<Vec<Row>>();
+                let proof = (Row::new_from_num(a0), period);
+                assert_eq!(proof, sqrt_continued_fraction(&Row::new_from_num(n)), "{n}");
+            }
         }
 
         #[test]
-        fn right_num_longer_test2() {
-            let row1 = Row::new_from_num(5);
-            let row2 = Row::new_from_num(10_000);
+        fn perfect_square_test() {
+            for n in [0u128, 1, 4, 144] {
+                let (a0, period) = sqrt_continued_fraction(&Row::new_from_num(n));
+                assert_eq!(Row::new_from_num(n.isqrt()), a0);
+                assert!(period.is_empty());
+            }
+        }
+    }
 
-            let sum = add(&row1, &row2);
-            assert_eq!(Row::new_from_num(10_005), sum);
+    mod pell {
+        use crate::{pell, Row};
+
+        #[test]
+        fn basic_test() {
+            for (n, x, y) in [
+                (2u128, 3u128, 2u128),
+                (3, 2, 1),
+                (5, 9, 4),
+                (7, 8, 3),
+                (13, 649, 180),
+            ] {
+                let proof = Some((Row::new_from_num(x), Row::new_from_num(y)));
+                assert_eq!(proof, pell(&Row::new_from_num(n)), "{n}");
+            }
         }
 
         #[test]
-        fn advanced_test() {
-            let row = Row::new_from_str("680564733841876926926749214863536422910").unwrap();
+        fn universal_test() {
+            for n in 2u128..=60 {
+                let proof = if n.isqrt().pow(2) == n {
+                    None
+                } else {
+                    (1u128..)
+                        .map(|y| (1 + n * y * y, y))
+                        .find(|(x2, _)| x2.isqrt().pow(2) == *x2)
+                        .map(|(x2, y)| (Row::new_from_num(x2.isqrt()), Row::new_from_num(y)))
+                };
 
-            let sum = add(&row, &row);
-            assert_eq!("1361129467683753853853498429727072845820", sum.to_number());
+                assert_eq!(proof, pell(&Row::new_from_num(n)), "{n}");
+            }
         }
 
         #[test]
-        fn addend1_nought_test() {
-            let addend1 = Row::nought();
-            let addend2 = Row::new_from_num(4321);
-            let sum = add(&addend1, &addend2);
-            assert_eq!(addend2, sum);
+        fn advanced_test() {
+            let proof = (
+                Row::new_from_num(1_766_319_049),
+                Row::new_from_num(226_153_980),
+            );
+            assert_eq!(Some(proof), pell(&Row::new_from_num(61)));
+
+            // x of 991 has 30 places
+            let (x, y) = pell(&Row::new_from_num(991)).unwrap();
+            assert_eq!("379516400906811930638014896080", x.to_number());
+            assert_eq!("12055735790331359447442538767", y.to_number());
         }
 
         #[test]
-        fn addend2_nought_test() {
-            let addend1 = Row::new_from_num(4321);
-            let addend2 = Row::nought();
-            let sum = add(&addend1, &addend2);
-            assert_eq!(addend1, sum);
+        fn perfect_square_test() {
+            for n in [0u128, 1, 4, 9, 10_000] {
+                assert_eq!(None, pell(&Row::new_from_num(n)), "{n}");
+            }
         }
     }
 
-    mod add_shortcut {
-        use crate::{add_shortcut, nought_raw, unity_raw};
+    mod ilog {
+        use crate::{ilog, pow, Row};
 
         #[test]
-        fn none_nought_test() {
-            assert_eq!(None, add_shortcut(&unity_raw(), &unity_raw()));
+        fn universal_test() {
+            for base in [2u128, 3, 7, 10, 16, 255] {
+                for num in 1..2_000u128 {
+                    let mut proof = 0;
+                    while base.pow(proof + 1) <= num {
+                        proof += 1;
+                    }
+
+                    let res = ilog(&Row::new_from_num(num), &Row::new_from_num(base));
+                    assert_eq!(Some(proof as u64), res, "{num} {base}");
+                }
+            }
         }
 
-        use alloc::vec;
         #[test]
-        fn r1_nought_test() {
-            let r1 = nought_raw();
-            let r2 = vec![1, 2, 3, 4];
-            let res = add_shortcut(&r1, &r2);
-            assert_eq!(Some(r2.clone()), res);
-            assert_ne!(r2.as_ptr(), res.unwrap().as_ptr());
+        fn advanced_test() {
+            let base = Row::new_from_num(12_345);
+            let num = pow(&base, 1000);
+            let less = crate::sub(&num, &Row::unity()).unwrap();
+
+            assert_eq!(Some(1000), ilog(&num, &base));
+            assert_eq!(Some(999), ilog(&less, &base));
         }
 
         #[test]
-        fn r2_nought_test() {
-            let r1 = vec![1, 2, 3, 4];
-            let r2 = nought_raw();
-            let res = add_shortcut(&r1, &r2);
-            assert_eq!(Some(r1.clone()), res);
-            assert_ne!(r1.as_ptr(), res.unwrap().as_ptr());
+        fn base_greater_test() {
+            let res = ilog(&Row::new_from_num(99), &Row::new_from_num(100));
+            assert_eq!(Some(0), res);
+        }
+
+        #[test]
+        fn invalid_input_test() {
+            let num = Row::new_from_num(99);
+            assert_eq!(None, ilog(&Row::nought(), &num));
+            assert_eq!(None, ilog(&num, &Row::nought()));
+            assert_eq!(None, ilog(&num, &Row::unity()));
         }
     }
 
-    /// Subtraction.
-    mod sub {
-        use crate::{sub, Row};
+    mod ilog2 {
+        use crate::{ilog2, pow, Row};
 
         #[test]
-        fn lesser_minuend_test() {
-            let minuend = Row::new_from_num(4);
-            let subtrahend = Row::new_from_num(5);
-
-            assert!(sub(&minuend, &subtrahend).is_none());
+        fn basic_test() {
+            assert_eq!(Some(0), ilog2(&Row::unity()));
+            assert_eq!(Some(10), ilog2(&Row::new_from_num(2047)));
+            assert_eq!(Some(11), ilog2(&Row::new_from_num(2048)));
+            assert_eq!(Some(127), ilog2(&Row::new_from_num(u128::MAX)));
+            assert_eq!(None, ilog2(&Row::nought()));
         }
 
         #[test]
-        fn universal_test() {
-            for triplet in [(99, 11, 88), (133, 133, 0), (90, 19, 71), (700, 699, 1)] {
-                let minuend = Row::new_from_num(triplet.0);
-                let subtrahend = Row::new_from_num(triplet.1);
-
-                let proof = Row::new_from_num(triplet.2);
-                let diff = sub(&minuend, &subtrahend);
-                assert!(diff.is_some());
-
-                assert_eq!(proof, diff.unwrap());
-            }
+        fn advanced_test() {
+            let num = pow(&Row::new_from_num(2), 4321);
+            assert_eq!(Some(4321), ilog2(&num));
         }
     }
 
-    mod sub_shortcut {
-        use crate::{nought_raw, sub_shortcut, Row};
+    mod ilog10 {
+        use crate::{ilog10, Row};
 
         #[test]
-        fn nought_subtrahend_test() {
-            let minuend = Row::new_from_num(40);
-            let subtrahend = nought_raw();
-
-            let proof = minuend.clone();
-            let test = sub_shortcut(&minuend.row, &subtrahend);
-            assert_eq!(Some(Some(proof)), test);
+        fn basic_test() {
+            assert_eq!(Some(0), ilog10(&Row::new_from_num(9)));
+            assert_eq!(Some(1), ilog10(&Row::new_from_num(10)));
+            assert_eq!(Some(38), ilog10(&Row::new_from_num(u128::MAX)));
+            assert_eq!(None, ilog10(&Row::nought()));
         }
+    }
 
-        #[test]
-        fn lesser_minuend_test() {
-            let minuend = Row::new_from_num(0).row;
-            let subtrahend = Row::new_from_num(1).row;
+    mod is_power_of {
+        use crate::{is_power_of, pow, Row};
 
-            let res = sub_shortcut(&minuend, &subtrahend);
-            assert_eq!(Some(None), res);
+        #[test]
+        fn basic_test() {
+            let base = Row::new_from_num(3);
+            assert!(is_power_of(&Row::unity(), &base));
+            assert!(is_power_of(&Row::new_from_num(3), &base));
+            assert!(is_power_of(&Row::new_from_num(243), &base));
+            assert!(!is_power_of(&Row::new_from_num(244), &base));
         }
 
         #[test]
-        fn equal_operands_test() {
-            let minuend = Row::new_from_num(1364).row;
-            let subtrahend = Row::new_from_num(1364).row;
-
-            let proof = Row::nought();
-            let test = sub_shortcut(&minuend, &subtrahend);
-            assert_eq!(Some(Some(proof)), test);
+        fn advanced_test() {
+            let base = Row::new_from_num(1_000_000_007);
+            let num = pow(&base, 77);
+            assert!(is_power_of(&num, &base));
+            assert!(!is_power_of(&crate::add(&num, &base), &base));
         }
 
         #[test]
-        fn greater_minuend_test() {
-            let minuend = Row::new_from_num(2).row;
-            let subtrahend = Row::new_from_num(1).row;
-
-            let res = sub_shortcut(&minuend, &subtrahend);
-            assert_eq!(None, res);
+        fn invalid_input_test() {
+            assert!(!is_power_of(&Row::nought(), &Row::new_from_num(3)));
+            assert!(!is_power_of(&Row::unity(), &Row::unity()));
         }
     }
 
-    /// Multiplication.
-    mod mul {
-        use crate::{mul, Row};
+    mod ten_power {
+        use crate::{ten_power, Row};
 
         #[test]
         fn basic_test() {
-            let row1 = Row::new_from_num(2);
-            let row2 = Row::new_from_num(3);
-            let prod = mul(&row1, &row2);
-            assert_eq!(&[6], &*prod);
+            assert_eq!(Row::unity(), ten_power(0));
+            assert_eq!(Row::new_from_num(1000), ten_power(3));
         }
+    }
+
+    mod factorial {
+        use crate::{factorial, mul, Row};
 
         #[test]
-        fn row1_nought_test() {
-            let row1 = Row::nought();
-            let row2 = Row::new_from_num(123456789_10111213);
-            let prod = mul(&row1, &row2);
-            let row = &prod.row;
-            assert_eq!(&[0], row.as_slice());
-            assert!(row.capacity() < row2.len());
+        fn basic_test() {
+            assert_eq!(Row::unity(), factorial(0));
+            assert_eq!(Row::unity(), factorial(1));
+            assert_eq!(Row::new_from_num(3_628_800), factorial(10));
         }
 
         #[test]
-        fn row2_nought_test() {
-            let row1 = Row::new_from_num(123456789_10111213);
-            let row2 = Row::nought();
-            let prod = mul(&row1, &row2);
-            let row = &prod.row;
-            assert_eq!(&[0], row.as_slice());
-            assert!(row.capacity() < row1.len());
+        fn universal_test() {
+            let mut proof = Row::unity();
+            for n in 1..300u32 {
+                proof = mul(&proof, &Row::new_from_num(n as u128));
+                assert_eq!(proof, factorial(n), "{n}");
+            }
         }
 
         #[test]
-        fn both_nought_test() {
-            let row1 = Row::nought();
-            let row2 = Row::nought();
-            let prod = mul(&row1, &row2);
-            assert_eq!(&[0], &*prod);
+        fn advanced_test() {
+            let res = factorial(1000).to_number();
+            let sum = res.bytes().map(|b| (b - b'0') as u32).sum::<u32>();
+
+            assert!(res.starts_with("40238726007709377354"));
+            assert_eq!(2568, res.len());
+            assert_eq!(10539, sum);
         }
 
         #[test]
-        fn advanced_test() {
-            let row = Row::new_from_num(u128::MAX);
-            let prod = mul(&row, &row);
-            let proof =
-                "115792089237316195423570985008687907852589419931798687112530834793049593217025";
-            assert_eq!(proof, prod.to_number());
+        #[cfg(feature = "ext-tests")]
+        fn advanced_test2() {
+            let res = factorial(100_000).to_number();
+
+            assert!(res.starts_with("28242294079603478742"));
+            assert_eq!(456_574, res.len());
         }
     }
 
-    mod mul_shortcut {
-        use crate::{mul_shortcut, nought_raw, unity_raw, Row};
+    mod prime_swing {
+        use crate::{prime_swing, Row};
 
         #[test]
-        fn factor1_nought_test() {
-            let row1 = nought_raw();
-            let row2 = Row::new_from_num(333_990).row;
-
-            let res = mul_shortcut(&row1, &row2);
-            assert_eq!(Some(row1), res);
+        fn basic_test() {
+            // 10! ÷(5!)² = 252
+            assert_eq!(Row::new_from_num(252), prime_swing(10));
+            // 11! ÷(5!)² = 2772
+            assert_eq!(Row::new_from_num(2772), prime_swing(11));
         }
+    }
+
+    mod double_factorial {
+        use crate::{double_factorial, Row};
 
         #[test]
-        fn factor2_nought_test() {
-            let row1 = Row::new_from_num(333_990).row;
-            let row2 = nought_raw();
+        fn universal_test() {
+            let mut proof = [1u128, 1];
+            for n in 0..50u32 {
+                if n > 1 {
+                    proof[n as usize % 2] *= n as u128;
+                }
 
-            let res = mul_shortcut(&row1, &row2);
-            assert_eq!(Some(row2), res);
+                let res = double_factorial(n);
+                assert_eq!(Row::new_from_num(proof[n as usize % 2]), res, "{n}");
+            }
         }
+    }
+
+    mod binomial {
+        use crate::{binomial, factorial, falling_factorial, Row};
+        use alloc::vec;
 
         #[test]
-        fn factor1_unity_test() {
-            let row1 = unity_raw();
-            let row2 = Row::new_from_num(333_990).row;
+        fn pascal_test() {
+            let mut row = vec![1u128];
+            for n in 0..100u32 {
+                for k in 0..=n {
+                    assert_eq!(
+                        Row::new_from_num(row[k as usize]),
+                        binomial(n, k),
+                        "{n} {k}"
+                    );
+                }
+
+                let mut next = vec![1u128; row.len() + 1];
+                for k in 1..row.len() {
+                    next[k] = row[k - 1] + row[k];
+                }
 
-            let res = mul_shortcut(&row1, &row2);
-            assert_eq!(Some(row2), res);
+                row = next;
+            }
         }
 
         #[test]
-        fn factor2_unity_test() {
-            let row1 = Row::new_from_num(333_990).row;
-            let row2 = unity_raw();
+        fn k_greater_test() {
+            assert_eq!(Row::nought(), binomial(3, 4));
+        }
 
-            let res = mul_shortcut(&row1, &row2);
-            assert_eq!(Some(row1), res);
+        #[test]
+        fn advanced_test() {
+            let res = binomial(1000, 500).to_number();
+            assert_eq!(300, res.len());
+            assert!(res.ends_with("96905863799821216320"));
         }
 
         #[test]
-        fn neither_unity_nor_nought_test() {
-            let row = Row::new_from_num(2).row;
+        fn large_n_test() {
+            let proof = Row::new_from_num(1_999_999_999_000_000_000);
+            assert_eq!(proof, binomial(2_000_000_000, 2));
+            assert_eq!(proof, binomial(2_000_000_000, 1_999_999_998));
+        }
 
-            let res = mul_shortcut(&row, &row);
-            assert_eq!(None, res);
+        #[test]
+        fn segment_test() {
+            for (n, k) in [(1_000_000_000, 100), (100_000, 300), (10_000, 500)] {
+                let ratio = crate::divrem(&falling_factorial(n, k), &factorial(k));
+                assert_eq!(Some((binomial(n, k), Row::nought())), ratio, "{n} {k}");
+            }
         }
     }
 
-    /// For base ≥ 0 and exponent ≥ 0 power can be viewed as nothing more
-    /// than repetetive multiplication with number in question.
-    /// 0º=1, 0¹=1×0, 0²=1×0×0, 0³=1×0×0×0, …
-    /// 1º=1, 1¹=1×1, 1²=1×1×1, 1³=1×1×1×1, …
-    /// 2º=1, 2¹=1×2, 2²=1×2×2, 2³=1×2×2×2, …
-    ///                   ⋮                   
-    mod pow {
-        use crate::{pow, Row};
+    mod binomial_factors {
+        use crate::binomial_factors;
+        use alloc::vec;
 
         #[test]
         fn basic_test() {
-            let row = Row::new_from_num(2);
-            assert_eq!(&[4], &*pow(&row, 2));
+            // C(10, 3) = 120 = 2³ ⋅3 ⋅5
+            let mut factors = binomial_factors(10, 3);
+            factors.sort();
+            assert_eq!(vec![2, 2, 2, 3, 5], factors);
+
+            // C(1000, 2) = 499500 = 2² ⋅3³ ⋅5³ ⋅37
+            let mut factors = binomial_factors(1000, 2);
+            factors.sort();
+            assert_eq!(vec![2, 2, 3, 3, 3, 5, 5, 5, 37], factors);
         }
+    }
+
+    mod multinomial {
+        use crate::{binomial, mul, multinomial, Row};
 
         #[test]
-        fn advanced_test2() {
-            let proof = Row::new_from_str("88817841970012523233890533447265625").unwrap();
-            let row = Row::new_from_num(25);
-            assert_eq!(proof, pow(&row, 25));
+        fn basic_test() {
+            // MISSISSIPPI
+            assert_eq!(Row::new_from_num(34_650), multinomial(&[1, 4, 4, 2]));
+            assert_eq!(Row::new_from_num(10), multinomial(&[2, 3]));
         }
 
         #[test]
-        fn advanced_test3() {
-            let proof = Row::new_from_str(
-                "949279437109690919948053832937215463733689853138782229364504479870922851876864",
-            )
-            .unwrap();
-
-            let row = Row::new_from_num(998);
-            assert_eq!(proof, pow(&row, 26));
+        fn degenerate_test() {
+            assert_eq!(Row::unity(), multinomial(&[]));
+            assert_eq!(Row::unity(), multinomial(&[0, 0]));
+            assert_eq!(Row::unity(), multinomial(&[7]));
         }
 
         #[test]
-        fn advanced_test4() {
-            let proof = Row::new_from_str(
-                "926336713898529563388567880069503262826159877325124512315660672063305037119488",
-            )
-            .unwrap();
-
-            let row = Row::new_from_num(2);
-            assert_eq!(proof, pow(&row, 259));
+        fn advanced_test() {
+            // C(a +b, b) ⋅C(a +b +c, c)
+            let proof = mul(&binomial(3_000, 1_000), &binomial(3_500, 500));
+            assert_eq!(proof, multinomial(&[500, 2_000, 1_000]));
         }
+    }
 
-        #[test]
-        #[cfg(feature = "ext-tests")]
-        // readme sample
-        fn advanced_test5() {
-            let row = Row::new_from_num(u128::MAX);
-            let pow = pow(&row, 500);
-            let number = pow.to_number();
+    mod falling_factorial {
+        use crate::{falling_factorial, Row};
 
-            assert!(number.starts_with("8312324609993336522"));
-            assert_eq!(19266, number.len());
+        #[test]
+        fn basic_test() {
+            assert_eq!(Row::new_from_num(720), falling_factorial(10, 3));
+            assert_eq!(Row::unity(), falling_factorial(10, 0));
+            assert_eq!(Row::unity(), falling_factorial(0, 0));
         }
 
         #[test]
-        fn zero_power_test() {
-            let row = Row::new_from_num(0);
-            let pow = pow(&row, 0);
-            assert_eq!(&[1], &*pow);
+        fn k_greater_test() {
+            assert_eq!(Row::nought(), falling_factorial(3, 4));
         }
+    }
+
+    mod rising_factorial {
+        use crate::{rising_factorial, Row};
 
         #[test]
-        fn one_power_test() {
-            let row = Row::new_from_num(3030);
-            let pow = pow(&row, 1);
-            assert_eq!(&[0, 3, 0, 3], &*pow);
+        fn basic_test() {
+            assert_eq!(Row::new_from_num(1320), rising_factorial(10, 3));
+            assert_eq!(Row::unity(), rising_factorial(10, 0));
+            assert_eq!(Row::unity(), rising_factorial(0, 0));
         }
 
         #[test]
-        fn power_of_nought_test() {
-            let row = Row::new_from_num(0);
-            let pow = pow(&row, 1000);
-            assert_eq!(&[0], &*pow);
+        fn nought_x_test() {
+            assert_eq!(Row::nought(), rising_factorial(0, 4));
         }
+    }
+
+    mod primorial {
+        use crate::{primorial, Row};
 
         #[test]
-        fn power_of_one_test() {
-            let row = Row::new_from_num(1);
-            let pow = pow(&row, u16::MAX);
-            assert_eq!(&[1], &*pow);
+        fn basic_test() {
+            assert_eq!(Row::unity(), primorial(1));
+            assert_eq!(Row::new_from_num(2), primorial(2));
+            assert_eq!(Row::new_from_num(30), primorial(6));
+            assert_eq!(Row::new_from_num(614_889_782_588_491_410), primorial(50));
         }
     }
 
-    mod pow_shortcut {
-        use super::nought;
-        use crate::{pow_shortcut, Row};
+    mod legendre_exp {
+        use crate::legendre_exp;
 
         #[test]
-        fn zero_power_test() {
-            let row = nought();
-            let pow = pow_shortcut(&row, 0);
-            assert_eq!(Some(Row::unity()), pow);
+        fn basic_test() {
+            assert_eq!(97, legendre_exp(100, 2));
+            assert_eq!(24, legendre_exp(100, 5));
+            assert_eq!(0, legendre_exp(4, 5));
         }
+    }
+
+    mod pow_u64 {
+        use crate::{pow, pow_u64, Row};
 
         #[test]
-        fn power_of_nought_test() {
-            let row = Row::nought();
-            let pow = pow_shortcut(&row.row, 1000);
-            assert_eq!(Some(row), pow);
+        fn basic_test() {
+            let base = Row::new_from_num(7);
+            assert_eq!(Row::unity(), pow_u64(&base, 0));
+            assert_eq!(pow(&base, 333), pow_u64(&base, 333));
         }
+    }
+
+    mod product_tree {
+        use crate::{product_tree, Row};
+        use alloc::vec::Vec;
 
         #[test]
-        fn one_power_test() {
-            let row = Row::new_from_num(3030);
-            let pow = pow_shortcut(&row.row, 1);
-            assert_eq!(Some(row), pow);
+        fn basic_test() {
+            assert_eq!(Row::unity(), product_tree(&[]));
+            assert_eq!(Row::new_from_num(24), product_tree(&[2, 3, 4]));
         }
 
         #[test]
-        fn power_of_one_test() {
-            let row = Row::unity();
-            let pow = pow_shortcut(&row.row, u16::MAX);
-            assert_eq!(Some(row), pow);
+        fn packing_test() {
+            let factors = [u64::MAX, u64::MAX, u64::MAX];
+            let square = u64::MAX as u128 * u64::MAX as u128;
+
+            let proof = crate::mul(
+                &Row::new_from_num(square),
+                &Row::new_from_num(u64::MAX as u128),
+            );
+            assert_eq!(proof, product_tree(&factors));
+            assert_eq!(Row::unity(), product_tree(&Vec::new()));
         }
     }
 
-    /// Division with remainder.
-    mod divrem {
-        use crate::{divrem, Row};
+    mod fibonacci {
+        use crate::{fibonacci, Row};
 
         #[test]
-        fn nought_divisor_test() {
-            let dividend = Row::new_from_num(1);
-            let divisor = Row::new_from_num(0);
+        fn universal_test() {
+            let (mut f, mut f_next) = (0u128, 1u128);
+            for n in 0..180 {
+                assert_eq!(Row::new_from_num(f), fibonacci(n), "{n}");
 
-            let ratrem = divrem(&dividend, &divisor);
-            assert!(ratrem.is_none());
+                let sum = f + f_next;
+                f = f_next;
+                f_next = sum;
+            }
         }
 
         #[test]
-        fn shorter_dividend_test() {
-            let dividend = Row::new_from_num(99);
-            let divisor = Row::new_from_num(999);
-
-            let ratrem = divrem(&dividend, &divisor);
-            assert!(ratrem.is_some());
-
-            let ratrem = ratrem.unwrap();
-
-            assert_eq!(Row::nought(), ratrem.0);
-            assert_eq!(dividend, ratrem.1);
+        fn advanced_test() {
+            let res = fibonacci(1000).to_number();
+            assert_eq!(209, res.len());
+            assert!(res.starts_with("43466557686937456435"));
         }
+    }
+
+    mod lucas {
+        use crate::{lucas, Row};
 
         #[test]
         fn universal_test() {
-            for quadruplet in [
-                (0, 100, 0, 0),
-                (99, 11, 9, 0),
-                (133, 133, 1, 0),
-                (90, 19, 4, 14),
-                (700, 699, 1, 1),
-                (700, 70, 10, 0),
-            ] {
-                let dividend = Row::new_from_num(quadruplet.0);
-                let divisor = Row::new_from_num(quadruplet.1);
-
-                let ratio = Row::new_from_num(quadruplet.2);
-                let remainder = Row::new_from_num(quadruplet.3);
-                let ratrem = divrem(&dividend, &divisor);
+            let (mut l, mut l_next) = (2u128, 1u128);
+            for n in 0..150 {
+                assert_eq!(Row::new_from_num(l), lucas(n), "{n}");
 
-                assert!(ratrem.is_some());
-                let ratrem = ratrem.unwrap();
-
-                assert_eq!(ratio, ratrem.0);
-                assert_eq!(remainder, ratrem.1);
+                let sum = l + l_next;
+                l = l_next;
+                l_next = sum;
             }
         }
     }
 
-    mod divrem_shortcut {
-        use crate::{divrem_shortcut, nought_raw, unity_raw, Row};
+    mod fibonacci_pair {
+        use crate::{fibonacci_pair, Row};
 
         #[test]
-        fn nought_divisor_test() {
-            let dividend = nought_raw();
-            let divisor = nought_raw();
+        fn basic_test() {
+            assert_eq!((Row::nought(), Row::unity()), fibonacci_pair(0));
+            assert_eq!(
+                (Row::new_from_num(55), Row::new_from_num(89)),
+                fibonacci_pair(10)
+            );
+        }
+    }
 
-            let ratrem = divrem_shortcut(&dividend, &divisor);
-            assert_eq!(Some(None), ratrem);
+    mod linear_recurrence {
+        use crate::{fibonacci, linear_recurrence, pow, Row};
+
+        fn rows(nums: &[u128]) -> alloc::vec::Vec<Row> {
+            nums.iter().map(|&n| Row::new_from_num(n)).collect()
         }
 
         #[test]
-        fn nought_dividend_test() {
-            let dividend = nought_raw();
-            let divisor = Row::new_from_num(4).row;
+        fn fibonacci_test() {
+            let coeffs = rows(&[1, 1]);
+            let initial = rows(&[0, 1]);
 
-            let proof = (Row::nought(), Row::nought());
-            let ratrem = divrem_shortcut(&dividend, &divisor);
-            assert_eq!(Some(Some(proof)), ratrem);
+            for n in [0, 1, 2, 10, 99, 500] {
+                let res = linear_recurrence(&coeffs, &initial, n);
+                assert_eq!(Some(fibonacci(n)), res, "{n}");
+            }
         }
 
         #[test]
-        fn unity_divisor_test() {
-            let dividend = nought_raw();
-            let divisor = unity_raw();
+        fn tribonacci_test() {
+            let coeffs = rows(&[1, 1, 1]);
+            let initial = rows(&[0, 0, 1]);
 
-            let proof = (Row::nought(), Row::nought());
-            let ratrem = divrem_shortcut(&dividend, &divisor);
-            assert_eq!(Some(Some(proof)), ratrem);
+            let proof = Row::new_from_num(53_324_762_928_098_149_064_722_658);
+            assert_eq!(Some(proof), linear_recurrence(&coeffs, &initial, 100));
         }
 
         #[test]
-        fn unity_divisor_test2() {
-            let dividend = Row::new_from_num(334_556);
-            let divisor = unity_raw();
+        fn general_coeffs_test() {
+            // aᵢ = 2aᵢ₋₁ +3aᵢ₋₂
+            let coeffs = rows(&[2, 3]);
+            let initial = rows(&[2, 1]);
 
-            let proof = (dividend.clone(), Row::nought());
-            let ratrem = divrem_shortcut(&dividend.row, &divisor);
-            assert_eq!(Some(Some(proof)), ratrem);
+            let proof = Row::new_from_str(
+                "277491363776345229693525586838772483139854825292292230361388303976311188",
+            )
+            .unwrap();
+            assert_eq!(Some(proof), linear_recurrence(&coeffs, &initial, 150));
         }
 
         #[test]
-        fn shorter_dividend_test() {
-            let dividend = Row::new_from_num(99);
-            let divisor = Row::new_from_num(999).row;
+        fn nought_coeff_test() {
+            // aᵢ = aᵢ₋₂
+            let coeffs = rows(&[0, 1]);
+            let initial = rows(&[4, 7]);
+
+            assert_eq!(
+                Some(Row::new_from_num(4)),
+                linear_recurrence(&coeffs, &initial, 1000)
+            );
+            assert_eq!(
+                Some(Row::new_from_num(7)),
+                linear_recurrence(&coeffs, &initial, 1001)
+            );
+        }
 
-            let proof = (Row::nought(), dividend.clone());
-            let ratrem = divrem_shortcut(&dividend.row, &divisor);
-            assert_eq!(Some(Some(proof)), ratrem);
+        #[test]
+        fn first_order_test() {
+            let coeffs = rows(&[3]);
+            let initial = rows(&[5]);
+
+            let proof = crate::mul(&pow(&Row::new_from_num(3), 200), &Row::new_from_num(5));
+            assert_eq!(Some(proof), linear_recurrence(&coeffs, &initial, 200));
+        }
+
+        #[test]
+        fn invalid_input_test() {
+            assert_eq!(None, linear_recurrence(&[], &[], 5));
+            assert_eq!(None, linear_recurrence(&rows(&[1, 1]), &rows(&[1]), 5));
+            assert_eq!(None, linear_recurrence(&rows(&[1]), &[], 5));
         }
     }
 
@@ -1973,6 +11974,83 @@ mod tests_of_units {
         }
     }
 
+    mod karatsuba {
+        use crate::{karatsuba, mulmul, RawRow, Row};
+        use alloc::vec::Vec;
+
+        // linear congruential generator of places
+        fn places(len: usize, mut seed: u64) -> RawRow {
+            let mut row = Vec::with_capacity(len);
+            for _ in 0..len {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                row.push((seed >> 33) as u8 % 10);
+            }
+
+            row.push(1);
+            row
+        }
+
+        #[test]
+        fn universal_test() {
+            for (len1, len2) in [(130, 130), (500, 300), (1000, 129), (700, 20), (257, 1000)] {
+                let r1 = places(len1, len1 as u64);
+                let r2 = places(len2, len2 as u64 + 1);
+
+                let proof = mulmul(&r1, &r2, 1);
+                assert_eq!(proof.row, karatsuba(&r1, &r2), "{len1} {len2}");
+            }
+        }
+
+        #[test]
+        fn zeros_test() {
+            // lower halves are noughts
+            let mut r1 = alloc::vec![0; 200];
+            r1.push(3);
+            let mut r2 = alloc::vec![0; 150];
+            r2.push(7);
+
+            let proof = mulmul(&r1, &r2, 1);
+            assert_eq!(proof.row, karatsuba(&r1, &r2));
+        }
+
+        #[test]
+        fn nought_test() {
+            let r1 = places(300, 1);
+            assert_eq!(Row::nought().row, karatsuba(&r1, &Row::nought().row));
+        }
+    }
+
+    mod convolution {
+        use crate::{convolution, Row};
+
+        #[test]
+        fn basic_test() {
+            let res = convolution(&Row::new_from_num(9999).row, &Row::new_from_num(999).row);
+            assert_eq!(Row::new_from_num(9_989_001).row, res);
+        }
+    }
+
+    mod karatsuba_split {
+        use crate::karatsuba_split;
+        use alloc::vec;
+
+        #[test]
+        fn basic_test() {
+            let (lo, hi) = karatsuba_split(&vec![1, 0, 0, 4, 5], 3);
+            assert_eq!(vec![1], lo);
+            assert_eq!(vec![4, 5], hi);
+        }
+
+        #[test]
+        fn nought_lo_test() {
+            let (lo, hi) = karatsuba_split(&vec![0, 0, 4], 2);
+            assert_eq!(vec![0], lo);
+            assert_eq!(vec![4], hi);
+        }
+    }
+
     /// Column addition fact notes:
     /// - When adding ones, maximum sum is 18=9+9.
     /// - Thus maximum tens sum is 1=⌊18÷10⌋.
@@ -2303,6 +12381,47 @@ mod tests_of_units {
         }
     }
 
+    mod division {
+        use crate::{division, Row};
+
+        #[test]
+        fn basic_test() {
+            let remratio = division(&Row::new_from_num(1234).row, &Row::new_from_num(7).row);
+            assert_eq!(&[2], &*remratio.0);
+            assert_eq!(&[6, 7, 1], &*remratio.1);
+        }
+
+        #[test]
+        fn universal_test() {
+            for (dividend, divisor) in [
+                (0u128, 3u128),
+                (5, 10),
+                (100, 10),
+                (1_000_000, 1),
+                (999_999_999_999, 9),
+                (u128::MAX, 3),
+                (u128::MAX, 18_446_744_073_709_551_557),
+                (123_456_789_123_456_789, 123_456_789),
+                (900_000_000_000_000_000_009, 30_000_000_003),
+            ] {
+                let remratio = division(
+                    &Row::new_from_num(dividend).row,
+                    &Row::new_from_num(divisor).row,
+                );
+
+                assert_eq!(Row::new_from_num(dividend % divisor).row, remratio.0);
+                assert_eq!(Row::new_from_num(dividend / divisor).row, remratio.1);
+            }
+        }
+
+        #[test]
+        fn nought_rem_test() {
+            let remratio = division(&Row::new_from_num(4900).row, &Row::new_from_num(49).row);
+            assert_eq!(&[0], &*remratio.0);
+            assert_eq!(&[0, 0, 1], &*remratio.1);
+        }
+    }
+
     /// Supporting method. Desinged to split ones from tens. Supports any range of tens.
     mod ones {
         use crate::ones;